@@ -1,17 +1,42 @@
 use chrono::Local;
 use ratatui::widgets::ListState;
-use std::env;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::mpsc::{self, Receiver};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::domain::config::AppConfig;
+use crate::domain::config::{AppConfig, Language};
 use crate::domain::models::*;
-use crate::infrastructure::api_client::ApiClient;
-use crate::infrastructure::config::{load_config, save_config};
+use crate::domain::targets::is_under_target;
+use crate::infrastructure::api_client::{ApiClient, rate_limit_retry_after};
+use crate::infrastructure::clipboard::copy_to_clipboard;
+use crate::infrastructure::config::{config_modified, load_config, save_config, try_load_config};
+use crate::infrastructure::credentials::{resolve_base_url, resolve_token};
+use crate::infrastructure::day_notes::{load_day_notes, save_day_notes};
+use crate::infrastructure::projects_cache::{cached_projects, replace_projects_cache};
+use crate::log;
+use crate::ui::helpers::display_client;
+use crate::utils::i18n::{Text, tr, tr_args};
+use crate::utils::ics::days_to_ics;
+use crate::utils::logging::{set_trace_enabled, trace_enabled};
 use crate::utils::parsing::*;
 
-const API_BASE: &str = "https://var.elaniin.com/api";
+const TOAST_DURATION: Duration = Duration::from_secs(2);
+const FRESH_HIGHLIGHT_DURATION: Duration = Duration::from_secs(8);
+const DAYS_PER_PAGE: usize = 7;
+const DAY_NOTE_MAX_LEN: usize = 120;
+const THEME_SAVE_DEBOUNCE: Duration = Duration::from_secs(1);
+const DAYS_SPLIT_MIN: u16 = 20;
+const DAYS_SPLIT_MAX: u16 = 80;
+const DAYS_SPLIT_STEP: u16 = 5;
+const RECENT_PROJECTS_CAP: usize = 5;
+const UNDO_STACK_CAP: usize = 10;
+// Per-field input caps (chars); the description cap comes from the config
+const INPUT_MAX_LEN: usize = 64;
+const TOKEN_MAX_LEN: usize = 512;
+const BASE_URL_MAX_LEN: usize = 256;
+const FORM_DATE_MAX_LEN: usize = 10;
+const FORM_MINUTES_MAX_LEN: usize = 8;
 
 const THEME_OPTIONS: &[&str] = &[
     "dracula",
@@ -29,14 +54,33 @@ const THEME_OPTIONS: &[&str] = &[
     "kanagawa",
     "everforest",
     "cyberpunk",
+    "high-contrast",
 ];
 
+pub const RANGE_OPTIONS: &[&str] = &["AUTO", "AUTO-WEEK", "AUTO-MONTH", "AUTO-QUARTER"];
+pub const RANGE_CUSTOM_LABEL: &str = "custom…";
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum InputMode {
     Normal,
     Editing,
     AddingEntry,
     Configuring,
+    NamingBookmark,
+    PickingBookmark,
+    Searching,
+    ConfirmingClearDay,
+    ConfirmingUndo,
+    AddingMinutes,
+    EditingDayNote,
+    PickingTemplate,
+}
+
+pub struct ClearDayReport {
+    pub date: String,
+    pub total: usize,
+    pub deleted: usize,
+    pub errors: Vec<String>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -64,9 +108,24 @@ pub struct EntryForm {
     pub filtered_indices: Vec<usize>,
     pub list_state: ListState,
     pub selected_project: Option<Project>,
+    // Leading filtered_indices that come from recent_project_ids
+    pub recent_count: usize,
+    // Set when the form edits an existing entry; submit updates it instead of creating one
+    pub editing_id: Option<i32>,
+    // Highlighted project whose name is shared by another match; the next Enter confirms it
+    pub ambiguous_project: Option<i32>,
+    // Last submit reached the API and failed; Ctrl+R resends the current values
+    pub submit_failed: bool,
 }
 
 impl EntryForm {
+    // Listed internal projects start as non-billable; other picks and edits keep the current flag
+    pub fn apply_billable_default(&mut self, config: &AppConfig, project_id: i32) {
+        if self.editing_id.is_none() && config.is_non_billable_project(project_id) {
+            self.is_billable = false;
+        }
+    }
+
     pub fn new(default_date: String) -> Self {
         Self {
             date: default_date,
@@ -78,6 +137,10 @@ impl EntryForm {
             filtered_indices: Vec::new(),
             list_state: ListState::default(),
             selected_project: None,
+            recent_count: 0,
+            editing_id: None,
+            ambiguous_project: None,
+            submit_failed: false,
         }
     }
 
@@ -100,6 +163,10 @@ impl EntryForm {
             filtered_indices: Vec::new(),
             list_state: ListState::default(),
             selected_project: None,
+            recent_count: 0,
+            editing_id: None,
+            ambiguous_project: None,
+            submit_failed: false,
         }
     }
 
@@ -122,12 +189,97 @@ impl EntryForm {
             FormField::Billable => FormField::Minutes,
         };
     }
+
+    // Focuses the first required field that would block the submit; false if none
+    pub fn focus_first_incomplete(&mut self) -> bool {
+        let project_missing = self.selected_project.is_none()
+            && self.project_search.trim().parse::<i32>().unwrap_or(0) <= 0;
+        let field = if self.date.trim().is_empty() {
+            FormField::Date
+        } else if project_missing {
+            FormField::ProjectId
+        } else if self.description.trim().is_empty() {
+            FormField::Description
+        } else if parse_minutes_input(&self.minutes) <= 0 {
+            FormField::Minutes
+        } else {
+            return false;
+        };
+        self.focused = field;
+        true
+    }
 }
 
 // Helper struct to return data from background thread
 pub struct BackgroundResult {
     pub days: Vec<Day>,
     pub status: String,
+    pub ok: bool,
+    pub range: DateRange,
+    pub time_entries: Vec<TimeEntry>,
+    pub projects_resolved: bool,
+}
+
+impl BackgroundResult {
+    fn failed(range: DateRange, status: String) -> Self {
+        Self {
+            days: Vec::new(),
+            status,
+            ok: false,
+            range,
+            time_entries: Vec::new(),
+            projects_resolved: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BillableFilter {
+    All,
+    Billable,
+    NonBillable,
+}
+
+impl BillableFilter {
+    pub fn next(self) -> Self {
+        match self {
+            BillableFilter::All => BillableFilter::Billable,
+            BillableFilter::Billable => BillableFilter::NonBillable,
+            BillableFilter::NonBillable => BillableFilter::All,
+        }
+    }
+
+    pub fn label(self, language: Language) -> &'static str {
+        let text = match self {
+            BillableFilter::All => Text::BillableAll,
+            BillableFilter::Billable => Text::BillableOnly,
+            BillableFilter::NonBillable => Text::BillableNon,
+        };
+        tr(language, text)
+    }
+
+    pub fn matches(self, entry: &Entry) -> bool {
+        match self {
+            BillableFilter::All => true,
+            BillableFilter::Billable => entry.billable,
+            BillableFilter::NonBillable => !entry.billable,
+        }
+    }
+}
+
+// Colours the toast; kept next to the message so the UI never parses status text
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StatusKind {
+    Info,
+    Error,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LoadState {
+    Idle,
+    Loading,
+    Loaded,
+    Failed(String),
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -144,12 +296,22 @@ pub struct ConfigForm {
     pub default_range: String,
     pub theme: String,
     pub theme_list_state: ListState,
+    pub range_list_state: ListState,
     pub focused: ConfigField,
+    // Message for the modal footer (no token, save/reset errors)
+    pub notice: Option<String>,
+}
+
+impl ConfigForm {
+    pub fn is_custom_range(&self) -> bool {
+        self.range_list_state.selected() == Some(RANGE_OPTIONS.len())
+    }
 }
 
 pub struct App {
     pub days: Vec<Day>,
     pub day_state: ListState,
+    pub day_view_state: ListState,
     pub entry_state: ListState,
     pub focus: AppFocus,
     pub status: String,
@@ -158,10 +320,56 @@ pub struct App {
     pub input: String,
     pub rx: Option<Receiver<BackgroundResult>>,
     pub rx_projects: Option<Receiver<Result<Vec<Project>, String>>>,
+    pub days_load: LoadState,
+    pub projects_load: LoadState,
     pub entry_form: Option<EntryForm>,
     pub projects: Vec<Project>,
+    // Projects per name, keyed like `project_name_key`; rebuilt once per project load
+    project_name_counts: HashMap<String, usize>,
     pub config: AppConfig,
     pub config_form: Option<ConfigForm>,
+    pub toast: Option<(String, StatusKind, Instant)>,
+    // Local annotations per date, stored in day_notes.json next to the config
+    pub day_notes: BTreeMap<String, String>,
+    // Entries created in this session (id, label), newest last; Ctrl+Z deletes the last one
+    undo_stack: Vec<(i32, String)>,
+    // For `config_reload_secs`: when config.toml was last checked and its mtime then
+    config_checked_at: Instant,
+    theme_save_at: Option<Instant>,
+    config_mtime: Option<SystemTime>,
+    // Entries added or changed by the last refresh, highlighted until `fresh_since` expires
+    pub fresh_entries: Vec<(String, Entry)>,
+    fresh_since: Option<Instant>,
+    pub hide_empty_days: bool,
+    pub bookmark_state: ListState,
+    pub template_state: ListState,
+    // Raw entries of the last load, kept to relabel days once projects arrive
+    pub cached_entries: Option<(String, Vec<TimeEntry>)>,
+    pub billable_filter: BillableFilter,
+    // (project id, name) picked with `p` on an entry; only its entries and days are shown
+    pub project_filter: Option<(i32, String)>,
+    // `m`: exact API minutes instead of hours in the lists
+    pub show_minutes: bool,
+    pub entry_view_state: ListState,
+    pub show_entry_preview: bool,
+    pub last_refresh: Instant,
+    // (day index, entry index) pairs matching `input` while Searching
+    pub search_results: Vec<(usize, usize)>,
+    pub search_state: ListState,
+    pub sort_under_target_first: bool,
+    // j/k in the days panel jump a week (`page_days`) instead of one day
+    pub week_steps: bool,
+    // Days panel width in percent, the entries panel gets the rest
+    pub days_split: u16,
+    // 0-minute entries removed by hide_zero_minute_entries in the loaded range
+    pub hidden_zero_entries: usize,
+    // Startup loads days and projects together and reports them in one status
+    startup_load: bool,
+    // Set after a 429; refreshes are held back until it passes
+    pub rate_limited_until: Option<Instant>,
+    retry_after_cooldown: bool,
+    // Minutes value already warned about by warn_below_minutes
+    short_entry_confirmed: Option<i32>,
 }
 
 impl App {
@@ -170,36 +378,46 @@ impl App {
 
         let mut date_range = initial_date_range();
         // Apply config overrides if present
-        if let Some(range_str) = &config.default_date_range {
-            if let Ok(r) = parse_date_range(range_str) {
-                date_range = r;
-            }
+        if let Some(range_str) = &config.default_date_range
+            && let Ok(r) = parse_date_range(range_str)
+        {
+            date_range = r;
         }
 
         let days = build_empty_days(&date_range);
         let has_token = config_has_token(&config);
 
         let status = if has_token {
-            "cargando...".to_string()
+            tr(config.language, Text::StatusLoading).to_string()
         } else {
-            "No hay token configurado. Completa la configuracion para empezar.".to_string()
+            tr(config.language, Text::StatusNoToken).to_string()
         };
 
-        let rx_projects = if has_token {
-            Some(spawn_load_projects(&config))
+        let background = has_token && !config.synchronous_load;
+        let rx_projects = background.then(|| spawn_load_projects(&config));
+        let rx_load = background.then(|| spawn_load(date_range.clone(), &config));
+        let initial_load = if has_token {
+            LoadState::Loading
         } else {
-            None
+            LoadState::Idle
         };
-
-        let rx_load = if has_token {
-            Some(spawn_load(date_range.clone(), &config))
+        let hide_empty_days = config.hide_empty_days;
+        let show_minutes = config.uses_minutes();
+        let days_split = config
+            .days_split_percent
+            .clamp(DAYS_SPLIT_MIN, DAYS_SPLIT_MAX);
+
+        // The last fetched projects stand in until the API answers
+        let projects = if has_token {
+            cached_projects()
         } else {
-            None
+            Vec::new()
         };
 
         let mut app = Self {
             days,
             day_state: ListState::default(),
+            day_view_state: ListState::default(),
             entry_state: ListState::default(),
             focus: AppFocus::Days,
             status,
@@ -208,20 +426,53 @@ impl App {
             input: String::new(),
             rx: rx_load,
             rx_projects,
+            days_load: initial_load.clone(),
+            projects_load: initial_load,
             entry_form: None,
-            projects: Vec::new(),
+            project_name_counts: project_name_counts(&projects),
+            projects,
             config,
             config_form: None,
+            toast: None,
+            day_notes: load_day_notes(),
+            undo_stack: Vec::new(),
+            config_checked_at: Instant::now(),
+            theme_save_at: None,
+            config_mtime: config_modified(),
+            fresh_entries: Vec::new(),
+            fresh_since: None,
+            hide_empty_days,
+            days_split,
+            hidden_zero_entries: 0,
+            startup_load: has_token,
+            bookmark_state: ListState::default(),
+            template_state: ListState::default(),
+            cached_entries: None,
+            billable_filter: BillableFilter::All,
+            project_filter: None,
+            show_minutes,
+            entry_view_state: ListState::default(),
+            show_entry_preview: false,
+            last_refresh: Instant::now(),
+            search_results: Vec::new(),
+            search_state: ListState::default(),
+            sort_under_target_first: false,
+            week_steps: false,
+            rate_limited_until: None,
+            retry_after_cooldown: false,
+            short_entry_confirmed: None,
         };
         // Ensure valid selection on init
         if !app.days.is_empty() {
             app.day_state.select(Some(0));
         }
+        if has_token && app.config.synchronous_load {
+            app.load_now();
+        }
 
         if !has_token {
             app.open_config();
-            app.status =
-                "No hay token configurado. Ingresa VAR Token y presiona Enter.".to_string();
+            app.set_config_notice(tr(app.config.language, Text::StatusNoTokenPrompt).to_string());
         }
 
         app
@@ -231,37 +482,39 @@ impl App {
         let config = load_config();
 
         let mut date_range = initial_date_range();
-        if let Some(range_str) = &config.default_date_range {
-            if let Ok(r) = parse_date_range(range_str) {
-                date_range = r;
-            }
+        if let Some(range_str) = &config.default_date_range
+            && let Ok(r) = parse_date_range(range_str)
+        {
+            date_range = r;
         }
 
         let days = build_empty_days(&date_range);
         let has_token = config_has_token(&config);
 
         let status = if has_token {
-            "cargando...".to_string()
-        } else {
-            "No hay token configurado. Define VAR_TOKEN o guarda var_token en la configuracion."
-                .to_string()
-        };
-
-        let rx_projects = if has_token {
-            Some(spawn_load_projects(&config))
+            tr(config.language, Text::StatusLoading).to_string()
         } else {
-            None
+            tr(config.language, Text::StatusNoTokenHeadless).to_string()
         };
 
-        let rx_load = if has_token {
-            Some(spawn_load(date_range.clone(), &config))
+        let background = has_token && !config.synchronous_load;
+        let rx_projects = background.then(|| spawn_load_projects(&config));
+        let rx_load = background.then(|| spawn_load(date_range.clone(), &config));
+        let initial_load = if has_token {
+            LoadState::Loading
         } else {
-            None
+            LoadState::Idle
         };
+        let hide_empty_days = config.hide_empty_days;
+        let show_minutes = config.uses_minutes();
+        let days_split = config
+            .days_split_percent
+            .clamp(DAYS_SPLIT_MIN, DAYS_SPLIT_MAX);
 
         let mut app = Self {
             days,
             day_state: ListState::default(),
+            day_view_state: ListState::default(),
             entry_state: ListState::default(),
             focus: AppFocus::Days,
             status,
@@ -270,70 +523,353 @@ impl App {
             input: String::new(),
             rx: rx_load,
             rx_projects,
+            days_load: initial_load.clone(),
+            projects_load: initial_load,
             entry_form: None,
             projects: Vec::new(),
+            project_name_counts: HashMap::new(),
             config,
             config_form: None,
+            toast: None,
+            day_notes: load_day_notes(),
+            undo_stack: Vec::new(),
+            config_checked_at: Instant::now(),
+            theme_save_at: None,
+            config_mtime: config_modified(),
+            fresh_entries: Vec::new(),
+            fresh_since: None,
+            hide_empty_days,
+            days_split,
+            hidden_zero_entries: 0,
+            startup_load: has_token,
+            bookmark_state: ListState::default(),
+            template_state: ListState::default(),
+            cached_entries: None,
+            billable_filter: BillableFilter::All,
+            project_filter: None,
+            show_minutes,
+            entry_view_state: ListState::default(),
+            show_entry_preview: false,
+            last_refresh: Instant::now(),
+            search_results: Vec::new(),
+            search_state: ListState::default(),
+            sort_under_target_first: false,
+            week_steps: false,
+            rate_limited_until: None,
+            retry_after_cooldown: false,
+            short_entry_confirmed: None,
         };
 
         if !app.days.is_empty() {
             app.day_state.select(Some(0));
         }
+        if has_token && app.config.synchronous_load {
+            app.load_now();
+        }
 
         app
     }
 
+    // synchronous_load: both requests finish before returning, so no receiver is ever pending
+    fn load_now(&mut self) {
+        self.start_projects_load();
+        self.start_days_load();
+        self.settle_startup_status();
+    }
+
+    fn start_days_load(&mut self) {
+        if self.config.synchronous_load {
+            self.rx = None;
+            let result = load_days(self.date_range.clone(), &self.config);
+            self.apply_days_result(result);
+        } else {
+            self.rx = Some(spawn_load(self.date_range.clone(), &self.config));
+            self.days_load = LoadState::Loading;
+        }
+    }
+
+    fn start_projects_load(&mut self) {
+        if self.config.synchronous_load {
+            self.rx_projects = None;
+            let result = load_projects(&self.config);
+            self.apply_projects_result(result);
+        } else {
+            self.rx_projects = Some(spawn_load_projects(&self.config));
+            self.projects_load = LoadState::Loading;
+        }
+    }
+
+    // Selection follows the same date and entry across reloads, not the same index
     pub fn set_days(&mut self, days: Vec<Day>) {
+        let selected_date = self.selected_day().map(|day| day.date.clone());
+        let selected_entry = self.selected_entry().map(entry_key);
         self.days = days;
         if self.days.is_empty() {
             self.day_state.select(None);
         } else {
-            let idx = self
-                .day_state
-                .selected()
-                .unwrap_or(0)
-                .min(self.days.len() - 1);
+            let idx = selected_date
+                .and_then(|date| self.days.iter().position(|day| day.date == date))
+                .unwrap_or_else(|| {
+                    self.day_state
+                        .selected()
+                        .unwrap_or(0)
+                        .min(self.days.len() - 1)
+                });
             self.day_state.select(Some(idx));
         }
+
+        if let Some(key) = selected_entry {
+            let position = self
+                .selected_day()
+                .and_then(|day| day.entries.iter().position(|entry| entry_key(entry) == key));
+            match position {
+                Some(position) => self.entry_state.select(Some(position)),
+                None if self.focus == AppFocus::Entries => {
+                    match self.visible_entry_indices().first().copied() {
+                        Some(first) => self.entry_state.select(Some(first)),
+                        None => self.focus_days(),
+                    }
+                }
+                None => {}
+            }
+        }
+        self.sync_day_selection();
+
+        // Results are (day, entry) indices into the old days; an open search reruns its query
+        if self.input_mode == InputMode::Searching {
+            self.update_search_results();
+        } else {
+            self.search_results.clear();
+        }
+    }
+
+    // Indices into `days` in display order; hidden days are skipped but kept in `days`
+    pub fn visible_day_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .days
+            .iter()
+            .enumerate()
+            .filter(|(_, day)| !self.hide_empty_days || day.total_hours() > 0.0)
+            .filter(|(_, day)| {
+                self.project_filter.is_none()
+                    || day
+                        .entries
+                        .iter()
+                        .any(|entry| self.matches_project_filter(entry))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        if self.sort_under_target_first {
+            let today = Local::now().date_naive();
+            let weekend = self.config.weekend_mode;
+            // Stable sort keeps the date order inside each group
+            indices.sort_by_key(|&idx| !is_under_target(&self.days[idx], today, weekend));
+        }
+        indices
+    }
+
+    pub fn toggle_sort_under_target(&mut self) {
+        self.sort_under_target_first = !self.sort_under_target_first;
+        let text = if self.sort_under_target_first {
+            Text::StatusSortUnderTarget
+        } else {
+            Text::StatusSortByDate
+        };
+        self.status = tr(self.config.language, text).to_string();
+    }
+
+    pub fn hidden_days_count(&self) -> usize {
+        self.days.len() - self.visible_day_indices().len()
+    }
+
+    // Projects sharing this one's name, itself included
+    pub fn project_name_count(&self, project: &Project) -> usize {
+        self.project_name_counts
+            .get(&project_name_key(&project.name))
+            .copied()
+            .unwrap_or(0)
     }
 
     pub fn selected_day(&self) -> Option<&Day> {
         self.day_state.selected().and_then(|idx| self.days.get(idx))
     }
 
-    pub fn selected_index(&self) -> usize {
-        self.day_state.selected().unwrap_or(0)
+    // Position of the selected day inside the visible list
+    pub fn selected_view_position(&self) -> Option<usize> {
+        let selected = self.day_state.selected()?;
+        self.visible_day_indices()
+            .iter()
+            .position(|&idx| idx == selected)
     }
 
     pub fn next_day(&mut self) {
-        if self.days.is_empty() {
+        let visible = self.visible_day_indices();
+        if visible.is_empty() {
             return;
         }
-        let next = match self.day_state.selected() {
-            Some(idx) if idx + 1 < self.days.len() => idx + 1,
+        let next = match self.selected_view_position() {
+            Some(pos) if pos + 1 < visible.len() => pos + 1,
             _ => 0,
         };
-        self.day_state.select(Some(next));
+        self.day_state.select(Some(visible[next]));
     }
 
     pub fn previous_day(&mut self) {
-        if self.days.is_empty() {
+        let visible = self.visible_day_indices();
+        if visible.is_empty() {
             return;
         }
-        let prev = match self.day_state.selected() {
-            Some(0) | None => self.days.len() - 1,
-            Some(idx) => idx - 1,
+        let prev = match self.selected_view_position() {
+            Some(0) | None => visible.len() - 1,
+            Some(pos) => pos - 1,
+        };
+        self.day_state.select(Some(visible[prev]));
+    }
+
+    // Moves a week's worth of rows; stops at the ends instead of wrapping
+    pub fn page_days(&mut self, forward: bool) {
+        let visible = self.visible_day_indices();
+        if visible.is_empty() {
+            return;
+        }
+        let target = match self.selected_view_position() {
+            Some(pos) if forward => (pos + DAYS_PER_PAGE).min(visible.len() - 1),
+            Some(pos) => pos.saturating_sub(DAYS_PER_PAGE),
+            None => 0,
+        };
+        self.day_state.select(Some(visible[target]));
+    }
+
+    pub fn toggle_week_steps(&mut self) {
+        self.week_steps = !self.week_steps;
+        let text = if self.week_steps {
+            Text::StatusWeekSteps
+        } else {
+            Text::StatusDaySteps
+        };
+        self.status = tr(self.config.language, text).to_string();
+    }
+
+    // Selects today's row if it is visible; the selection is left alone otherwise
+    pub fn select_today(&mut self) -> bool {
+        let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+        let Some(idx) = self
+            .visible_day_indices()
+            .into_iter()
+            .find(|&idx| self.days[idx].date == today)
+        else {
+            return false;
+        };
+        self.day_state.select(Some(idx));
+        if self.focus == AppFocus::Entries {
+            self.focus_days();
+        }
+        true
+    }
+
+    pub fn toggle_hide_empty_days(&mut self) {
+        self.hide_empty_days = !self.hide_empty_days;
+        self.sync_day_selection();
+        self.status = if self.hide_empty_days {
+            tr_args(
+                self.config.language,
+                Text::StatusEmptyDaysHidden,
+                &[&self.hidden_days_count().to_string()],
+            )
+        } else {
+            tr(self.config.language, Text::StatusShowingAllDays).to_string()
+        };
+    }
+
+    pub fn widen_days_panel(&mut self) {
+        self.set_days_split(self.days_split.saturating_add(DAYS_SPLIT_STEP));
+    }
+
+    pub fn narrow_days_panel(&mut self) {
+        self.set_days_split(self.days_split.saturating_sub(DAYS_SPLIT_STEP));
+    }
+
+    fn set_days_split(&mut self, percent: u16) {
+        let percent = percent.clamp(DAYS_SPLIT_MIN, DAYS_SPLIT_MAX);
+        let lang = self.config.language;
+        if percent == self.days_split {
+            self.status = tr_args(lang, Text::StatusSplitLimit, &[&percent.to_string()]);
+            return;
+        }
+        self.days_split = percent;
+
+        let mut new_config = self.config.clone();
+        new_config.days_split_percent = percent;
+        self.status = match save_config(&new_config) {
+            Ok(()) => {
+                self.config = new_config;
+                tr_args(lang, Text::StatusSplit, &[&percent.to_string()])
+            }
+            Err(error) => tr_args(
+                lang,
+                Text::StatusSplitNotSaved,
+                &[&percent.to_string(), &error],
+            ),
+        };
+    }
+
+    // Applied right away; saved once the user stops cycling
+    pub fn cycle_theme(&mut self, forward: bool) {
+        let current = canonical_theme_slug(&self.config.theme);
+        let index = THEME_OPTIONS
+            .iter()
+            .position(|theme| *theme == current)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % THEME_OPTIONS.len()
+        } else {
+            (index + THEME_OPTIONS.len() - 1) % THEME_OPTIONS.len()
         };
-        self.day_state.select(Some(prev));
+        self.config.theme = THEME_OPTIONS[next].to_string();
+        self.theme_save_at = Some(Instant::now() + THEME_SAVE_DEBOUNCE);
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusTheme,
+            &[
+                THEME_OPTIONS[next],
+                &(next + 1).to_string(),
+                &THEME_OPTIONS.len().to_string(),
+            ],
+        );
+    }
+
+    pub fn flush_theme_save(&mut self) {
+        if self.theme_save_at.take().is_none() {
+            return;
+        }
+        if let Err(error) = save_config(&self.config) {
+            self.status = tr_args(
+                self.config.language,
+                Text::StatusThemeSaveError,
+                &[&self.config.theme, &error],
+            );
+        }
+    }
+
+    // Keeps the selection on a visible day after the view changes
+    fn sync_day_selection(&mut self) {
+        if self.selected_view_position().is_some() {
+            return;
+        }
+        let first = self.visible_day_indices().first().copied();
+        if first.is_some() || self.hide_empty_days {
+            self.day_state.select(first);
+        }
+        if self.focus == AppFocus::Entries {
+            self.focus_days();
+        }
     }
 
     // Entry navigation methods
     pub fn focus_entries(&mut self) {
-        if let Some(day) = self.selected_day() {
-            if !day.entries.is_empty() {
-                self.focus = AppFocus::Entries;
-                self.entry_state.select(Some(0));
-            }
+        if let Some(&first) = self.visible_entry_indices().first() {
+            self.focus = AppFocus::Entries;
+            self.entry_state.select(Some(first));
         }
     }
 
@@ -342,29 +878,123 @@ impl App {
         self.entry_state.select(None);
     }
 
+    // Indices into the selected day's entries that pass the billable filter
+    pub fn visible_entry_indices(&self) -> Vec<usize> {
+        self.selected_day()
+            .map(|day| {
+                day.entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, entry)| {
+                        self.billable_filter.matches(entry) && self.matches_project_filter(entry)
+                    })
+                    .map(|(idx, _)| idx)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn selected_entry_view_position(&self) -> Option<usize> {
+        let selected = self.entry_state.selected()?;
+        self.visible_entry_indices()
+            .iter()
+            .position(|&idx| idx == selected)
+    }
+
     pub fn next_entry(&mut self) {
-        if let Some(day) = self.selected_day() {
-            if day.entries.is_empty() {
-                return;
-            }
-            let next = match self.entry_state.selected() {
-                Some(idx) if idx + 1 < day.entries.len() => idx + 1,
-                _ => 0,
-            };
-            self.entry_state.select(Some(next));
+        let visible = self.visible_entry_indices();
+        if visible.is_empty() {
+            return;
         }
+        let next = match self.selected_entry_view_position() {
+            Some(pos) if pos + 1 < visible.len() => pos + 1,
+            _ => 0,
+        };
+        self.entry_state.select(Some(visible[next]));
     }
 
     pub fn previous_entry(&mut self) {
-        if let Some(day) = self.selected_day() {
-            if day.entries.is_empty() {
-                return;
+        let visible = self.visible_entry_indices();
+        if visible.is_empty() {
+            return;
+        }
+        let prev = match self.selected_entry_view_position() {
+            Some(0) | None => visible.len() - 1,
+            Some(pos) => pos - 1,
+        };
+        self.entry_state.select(Some(visible[prev]));
+    }
+
+    pub fn cycle_billable_filter(&mut self) {
+        self.billable_filter = self.billable_filter.next();
+        if self.focus == AppFocus::Entries && self.selected_entry_view_position().is_none() {
+            match self.visible_entry_indices().first() {
+                Some(&first) => self.entry_state.select(Some(first)),
+                None => self.focus_days(),
+            }
+        }
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusBillableFilter,
+            &[self.billable_filter.label(self.config.language)],
+        );
+    }
+
+    // Ids when both sides have one; names for entries the API sent without an id
+    pub fn matches_project_filter(&self, entry: &Entry) -> bool {
+        match &self.project_filter {
+            None => true,
+            Some((id, _)) if *id != 0 && entry.project_id != 0 => entry.project_id == *id,
+            Some((_, name)) => entry.project == *name,
+        }
+    }
+
+    // Hours logged on the filtered project across the loaded range
+    pub fn project_filter_hours(&self) -> f32 {
+        self.days
+            .iter()
+            .flat_map(|day| &day.entries)
+            .filter(|entry| self.matches_project_filter(entry))
+            .map(|entry| entry.hours)
+            .sum()
+    }
+
+    pub fn toggle_show_minutes(&mut self) {
+        self.show_minutes = !self.show_minutes;
+        let text = if self.show_minutes {
+            Text::StatusExactMinutes
+        } else {
+            Text::StatusHourDurations
+        };
+        self.status = tr(self.config.language, text).to_string();
+    }
+
+    pub fn toggle_project_filter(&mut self) {
+        if self.project_filter.take().is_some() {
+            self.status = tr(self.config.language, Text::StatusProjectFilterCleared).to_string();
+            return;
+        }
+        let Some(entry) = self
+            .selected_entry()
+            .filter(|_| self.focus == AppFocus::Entries)
+        else {
+            return;
+        };
+        self.set_project_filter(entry.project_id, entry.project.clone());
+    }
+
+    pub fn set_project_filter(&mut self, project_id: i32, name: String) {
+        self.status = tr_args(self.config.language, Text::StatusProjectFilter, &[&name]);
+        self.project_filter = Some((project_id, name));
+        if self.visible_day_indices().is_empty() {
+            return;
+        }
+        self.sync_day_selection();
+        if self.focus == AppFocus::Entries && self.selected_entry_view_position().is_none() {
+            match self.visible_entry_indices().first() {
+                Some(&first) => self.entry_state.select(Some(first)),
+                None => self.focus_days(),
             }
-            let prev = match self.entry_state.selected() {
-                Some(0) | None => day.entries.len() - 1,
-                Some(idx) => idx - 1,
-            };
-            self.entry_state.select(Some(prev));
         }
     }
 
@@ -382,131 +1012,1156 @@ impl App {
         }
 
         // Get data from selected entry
-        let (date, project_name, description, minutes) = if let Some(day) = self.selected_day() {
-            if let Some(entry) = self
-                .entry_state
-                .selected()
-                .and_then(|idx| day.entries.get(idx))
-            {
-                let mins = (entry.hours * 60.0) as i32;
-                (
-                    day.date.clone(),
-                    entry.project.clone(),
-                    entry.note.clone(),
-                    mins,
-                )
+        let (date, project_name, description, minutes, billable) =
+            if let Some(day) = self.selected_day() {
+                if let Some(entry) = self
+                    .entry_state
+                    .selected()
+                    .and_then(|idx| day.entries.get(idx))
+                {
+                    (
+                        day.date.clone(),
+                        entry.project.clone(),
+                        entry.note.clone(),
+                        entry.minutes,
+                        entry.billable,
+                    )
+                } else {
+                    return;
+                }
             } else {
                 return;
-            }
-        } else {
-            return;
-        };
+            };
 
-        // Create form with pre-filled data (default is_billable to true since we don't store it)
+        // Create form with pre-filled data
         self.entry_form = Some(EntryForm::with_entry_data(
             date,
             project_name,
             description,
             minutes,
-            true,
+            billable,
         ));
         self.input_mode = InputMode::AddingEntry;
         self.update_project_filter();
     }
 
-    pub fn refresh(&mut self) {
-        if !config_has_token(&self.config) {
-            self.rx = None;
-            self.rx_projects = None;
-            self.open_config();
-            self.status =
-                "No hay token configurado. Ingresa VAR Token y presiona Enter.".to_string();
+    pub fn open_edit_entry(&mut self) {
+        if self.focus != AppFocus::Entries {
+            return;
+        }
+        let Some((date, entry)) = self
+            .selected_day()
+            .zip(self.selected_entry())
+            .map(|(day, entry)| (day.date.clone(), entry.clone()))
+        else {
+            return;
+        };
+        if entry.id == 0 {
+            self.status = tr(self.config.language, Text::StatusEntryMissingId).to_string();
             return;
         }
 
-        self.status = "actualizando...".to_string();
-        self.rx = Some(spawn_load(self.date_range.clone(), &self.config));
+        let mut form = EntryForm::with_entry_data(
+            date,
+            entry.project.clone(),
+            entry.note.clone(),
+            entry.minutes,
+            entry.billable,
+        );
+        form.editing_id = Some(entry.id);
+        form.selected_project = self
+            .projects
+            .iter()
+            .find(|project| {
+                if entry.project_id != 0 {
+                    project.id == entry.project_id
+                } else {
+                    project.name == entry.project
+                }
+            })
+            .cloned();
+        self.entry_form = Some(form);
+        self.short_entry_confirmed = None;
+        self.input_mode = InputMode::AddingEntry;
+        self.update_project_filter();
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusEditingEntry,
+            &[&entry.id.to_string()],
+        );
+    }
 
-        if self.projects.is_empty() {
-            self.rx_projects = Some(spawn_load_projects(&self.config));
+    // Selects the `index`-th entry of `date` and opens it for editing; returns its id
+    pub fn load_entry(&mut self, date: &str, index: usize) -> Result<i32, String> {
+        let day_idx = self
+            .days
+            .iter()
+            .position(|day| day.date == date)
+            .ok_or_else(|| tr_args(self.config.language, Text::StatusDayOutOfRange, &[date]))?;
+        let entry_id = self.days[day_idx]
+            .entries
+            .get(index)
+            .map(|entry| entry.id)
+            .ok_or_else(|| {
+                tr_args(
+                    self.config.language,
+                    Text::StatusNoEntryAtIndex,
+                    &[date, &index.to_string()],
+                )
+            })?;
+        if entry_id == 0 {
+            return Err(tr(self.config.language, Text::StatusEntryMissingId).to_string());
         }
+
+        self.select_entry_at(day_idx, index);
+        self.open_edit_entry();
+        Ok(entry_id)
     }
 
-    pub fn start_input(&mut self) {
-        self.input_mode = InputMode::Editing;
-        self.input = format!("{}..{}", self.date_range.start, self.date_range.end);
+    pub fn copy_entry_as_command(&mut self) {
+        if self.focus != AppFocus::Entries {
+            return;
+        }
+
+        let lang = self.config.language;
+        let result = self.selected_entry_command().and_then(|command| {
+            copy_to_clipboard(&command)
+                .map(|_| tr(lang, Text::StatusCommandCopied).to_string())
+                .map_err(|e| tr_args(lang, Text::StatusCopyError, &[&e.to_string()]))
+        });
+        self.toast_result(result);
     }
 
-    pub fn cancel_input(&mut self) {
-        self.input_mode = InputMode::Normal;
-        self.input.clear();
+    pub fn copy_day_as_json(&mut self) {
+        let Some(json) = self.selected_day_json() else {
+            return;
+        };
+
+        let lang = self.config.language;
+        self.toast_result(match copy_to_clipboard(&json) {
+            Ok(_) => Ok(tr(lang, Text::StatusDayJsonCopied).to_string()),
+            Err(e) => Err(tr_args(lang, Text::StatusCopyError, &[&e.to_string()])),
+        });
     }
 
-    pub fn submit_input(&mut self) {
-        match parse_date_range(&self.input) {
-            Ok(range) => {
-                self.date_range = range;
-                self.input_mode = InputMode::Normal;
-                self.input.clear();
-                self.set_days(build_empty_days(&self.date_range));
-                self.refresh();
-            }
-            Err(error) => {
-                self.status = format!("estado: {}", error);
-            }
-        }
+    pub fn toggle_http_trace(&mut self) {
+        let enabled = !trace_enabled();
+        set_trace_enabled(enabled);
+        let text = if enabled {
+            Text::StatusTraceOn
+        } else {
+            Text::StatusTraceOff
+        };
+        self.status = tr(self.config.language, text).to_string();
+        self.show_toast(self.status.clone(), StatusKind::Info);
     }
 
-    pub fn input_push(&mut self, value: char) {
-        if value.is_ascii() && self.input.len() < 64 {
-            self.input.push(value);
-        }
+    pub fn copy_range_label(&mut self) {
+        let lang = self.config.language;
+        let label = self.date_range.label();
+        self.toast_result(match copy_to_clipboard(&label) {
+            Ok(_) => Ok(tr_args(lang, Text::StatusRangeCopied, &[&label])),
+            Err(e) => Err(tr_args(lang, Text::StatusCopyError, &[&e.to_string()])),
+        });
     }
 
-    pub fn input_backspace(&mut self) {
-        self.input.pop();
+    // One line for chat status updates; `whole_range` sums every loaded day instead
+    pub fn copy_summary(&mut self, whole_range: bool) {
+        let lang = self.config.language;
+        let hhmm = self.config.uses_hhmm();
+        let summary = if whole_range {
+            let hours: f32 = self.days.iter().map(Day::total_hours).sum();
+            let entries: usize = self.days.iter().map(|day| day.entries.len()).sum();
+            let worked = self
+                .days
+                .iter()
+                .filter(|day| day.total_hours() > 0.0)
+                .count();
+            tr_args(
+                lang,
+                Text::RangeSummary,
+                &[
+                    &self.date_range.label(),
+                    &format_total(hours, hhmm, self.config.compact_totals),
+                    &entries.to_string(),
+                    &worked.to_string(),
+                ],
+            )
+        } else {
+            let Some(day) = self.selected_day() else {
+                return;
+            };
+            tr_args(
+                lang,
+                Text::DaySummary,
+                &[
+                    &day.date,
+                    &format_duration(day.total_hours(), hhmm),
+                    &day.entries.len().to_string(),
+                ],
+            )
+        };
+        self.toast_result(match copy_to_clipboard(&summary) {
+            Ok(_) => Ok(tr_args(lang, Text::StatusSummaryCopied, &[&summary])),
+            Err(e) => Err(format!(
+                "{}; {summary}",
+                tr_args(lang, Text::StatusCopyError, &[&e.to_string()])
+            )),
+        });
     }
 
-    pub fn wait_background_load(&mut self, timeout: Duration) {
-        let deadline = Instant::now() + timeout;
+    pub fn selected_day_json(&self) -> Option<String> {
+        let day = self.selected_day()?;
+        let mut value = serde_json::to_value(day).ok()?;
+        if let Some(map) = value.as_object_mut() {
+            map.insert("total_hours".to_string(), day.total_hours().into());
+        }
+        serde_json::to_string_pretty(&value).ok()
+    }
 
-        if let Some(rx) = self.rx.take() {
-            let remaining = deadline.saturating_duration_since(Instant::now());
-            match rx.recv_timeout(remaining) {
-                Ok(result) => {
-                    self.set_days(result.days);
-                    self.status = result.status;
+    // Writes the loaded range to ./vartui-<start>_<end>.ics, honoring the billable filter
+    pub fn export_range_ics(&mut self) {
+        let filter = self.billable_filter;
+        let days: Vec<Day> = self
+            .days
+            .iter()
+            .map(|day| Day {
+                date: day.date.clone(),
+                entries: day
+                    .entries
+                    .iter()
+                    .filter(|entry| filter.matches(entry))
+                    .cloned()
+                    .collect(),
+            })
+            .collect();
+        let path = format!(
+            "vartui-{}_{}.ics",
+            self.date_range.start, self.date_range.end
+        );
+        self.toast_result(match std::fs::write(&path, days_to_ics(&days)) {
+            Ok(()) => Ok(tr_args(
+                self.config.language,
+                Text::StatusExported,
+                &[&path],
+            )),
+            Err(error) => Err(tr_args(
+                self.config.language,
+                Text::StatusExportError,
+                &[&error.to_string()],
+            )),
+        });
+    }
+
+    // Asks the user to type the entry count before wiping the selected day
+    pub fn start_clear_day(&mut self) {
+        let Some(day) = self.selected_day() else {
+            return;
+        };
+        let count = day.entries.len();
+        if count == 0 {
+            self.status = tr(self.config.language, Text::StatusDayEmpty).to_string();
+            return;
+        }
+        if day.entries.iter().any(|entry| entry.id == 0) {
+            self.status = tr(self.config.language, Text::StatusDayMissingIds).to_string();
+            return;
+        }
+        let count = count.to_string();
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusClearDayPrompt,
+            &[&count, &day.date, &count],
+        );
+        self.input_mode = InputMode::ConfirmingClearDay;
+        self.input.clear();
+    }
+
+    pub fn start_add_minutes(&mut self) {
+        if self.focus != AppFocus::Entries {
+            return;
+        }
+        let Some(entry) = self.selected_entry() else {
+            return;
+        };
+        let lang = self.config.language;
+        let target = if self.config.add_minutes_creates_entry() {
+            tr_args(lang, Text::AddMinutesNewTarget, &[&entry.project])
+        } else {
+            format!(
+                "{} ({})",
+                entry.project,
+                format_duration(entry.hours, false)
+            )
+        };
+        self.status = tr_args(lang, Text::StatusAddMinutesPrompt, &[&target]);
+        self.input_mode = InputMode::AddingMinutes;
+        self.input.clear();
+    }
+
+    pub fn confirm_add_minutes(&mut self) {
+        let delta = parse_minutes_input(self.input.trim());
+        self.cancel_input();
+        if delta <= 0 {
+            self.status = tr(self.config.language, Text::StatusInvalidTime).to_string();
+            return;
+        }
+        if let Err(error) = self.add_minutes_to_entry(delta) {
+            self.status = tr_args(self.config.language, Text::StatusAddMinutesError, &[&error]);
+            self.note_rate_limit(&error);
+        }
+    }
+
+    // Adds `delta` minutes to the selected entry, or logs them as a new entry with the
+    // same project and note when add_minutes_mode is "new". Returns the id of the entry
+    // holding the minutes (the created one in "new" mode, if the API sent it) and its minutes.
+    pub fn add_minutes_to_entry(&mut self, delta: i32) -> Result<(Option<i32>, i32), String> {
+        let language = self.config.language;
+        let day = self
+            .selected_day()
+            .ok_or(tr(language, Text::StatusNoDaySelected))?;
+        let entry = self
+            .selected_entry()
+            .ok_or(tr(language, Text::StatusNoEntrySelected))?;
+        let date = day.date.clone();
+        let entry = entry.clone();
+        let creates_entry = self.config.add_minutes_creates_entry();
+        if !creates_entry && entry.id == 0 {
+            return Err(tr(self.config.language, Text::StatusEntryMissingId).to_string());
+        }
+        let project_id = if entry.project_id != 0 {
+            entry.project_id
+        } else {
+            self.projects
+                .iter()
+                .find(|project| project.name == entry.project)
+                .map(|project| project.id)
+                .ok_or_else(|| {
+                    tr_args(language, Text::StatusProjectIdNotFound, &[&entry.project])
+                })?
+        };
+        if let Some(remaining) = self.rate_limit_remaining() {
+            return Err(rate_limit_status(self.config.language, remaining));
+        }
+
+        let token = resolve_token(&self.config);
+        if token.is_empty() {
+            return Err(tr(self.config.language, Text::StatusNoTokenShort).to_string());
+        }
+        let client = ApiClient::new(resolve_base_url(&self.config), token)?
+            .with_create_endpoint(&self.config.create_method, &self.config.create_path)?;
+
+        let (id, minutes) = if creates_entry {
+            let created =
+                client.create_time_entry(&date, project_id, &entry.note, delta, entry.billable)?;
+            let id = created.as_ref().map(|created| created.id);
+            self.push_undo(created);
+            (id, delta)
+        } else {
+            let total = entry.minutes + delta;
+            client.update_time_entry(
+                entry.id,
+                &date,
+                project_id,
+                &entry.note,
+                total,
+                entry.billable,
+            )?;
+            (Some(entry.id), total)
+        };
+
+        let lang = self.config.language;
+        let delta_label = delta.to_string();
+        self.status = if creates_entry {
+            tr_args(
+                lang,
+                Text::StatusMinutesLogged,
+                &[&delta_label, &entry.project],
+            )
+        } else {
+            tr_args(
+                lang,
+                Text::StatusMinutesAdded,
+                &[&delta_label, &entry.id.to_string(), &minutes.to_string()],
+            )
+        };
+        self.show_toast(self.status.clone(), StatusKind::Info);
+        self.refresh();
+        Ok((id, minutes))
+    }
+
+    pub fn confirm_clear_day(&mut self) {
+        let expected = self.selected_day().map(|day| day.entries.len().to_string());
+        let confirmed = expected.is_some_and(|count| self.input.trim() == count);
+        self.cancel_input();
+        if !confirmed {
+            self.status = tr(self.config.language, Text::StatusClearDayMismatch).to_string();
+            return;
+        }
+        if let Err(error) = self.delete_day_entries() {
+            self.status = tr_args(self.config.language, Text::StatusClearDayError, &[&error]);
+        }
+    }
+
+    // Deletes entries one by one; a 429 stops the run and counts the rest as failed
+    pub fn delete_day_entries(&mut self) -> Result<ClearDayReport, String> {
+        let language = self.config.language;
+        let day = self
+            .selected_day()
+            .ok_or(tr(language, Text::StatusNoDaySelected))?;
+        let date = day.date.clone();
+        let ids: Vec<i32> = day.entries.iter().map(|entry| entry.id).collect();
+        if ids.contains(&0) {
+            return Err(tr(language, Text::StatusNoEntryIds).to_string());
+        }
+        if let Some(remaining) = self.rate_limit_remaining() {
+            return Err(rate_limit_status(self.config.language, remaining));
+        }
+
+        let token = resolve_token(&self.config);
+        if token.is_empty() {
+            return Err(tr(self.config.language, Text::StatusNoTokenShort).to_string());
+        }
+        let client = ApiClient::new(resolve_base_url(&self.config), token)?;
+
+        let mut report = ClearDayReport {
+            date,
+            total: ids.len(),
+            deleted: 0,
+            errors: Vec::new(),
+        };
+        for (index, id) in ids.iter().enumerate() {
+            match client.delete_time_entry(*id) {
+                Ok(()) => report.deleted += 1,
+                Err(error) => {
+                    report.errors.push(format!("{id}: {error}"));
+                    if self.note_rate_limit(&error) {
+                        let skipped = &ids[index + 1..];
+                        report.errors.extend(
+                            skipped.iter().map(|id| {
+                                tr_args(language, Text::StatusSkipped, &[&id.to_string()])
+                            }),
+                        );
+                        break;
+                    }
                 }
-                Err(_) => {}
+            }
+        }
+
+        if report.deleted > 0 {
+            self.refresh();
+        }
+        let lang = self.config.language;
+        let deleted = report.deleted.to_string();
+        self.toast_result(if report.errors.is_empty() {
+            Ok(tr_args(
+                lang,
+                Text::StatusClearDayDone,
+                &[&deleted, &report.date],
+            ))
+        } else {
+            Err(tr_args(
+                lang,
+                Text::StatusClearDayPartial,
+                &[
+                    &deleted,
+                    &report.total.to_string(),
+                    &report.date,
+                    &report.errors.len().to_string(),
+                    &report.errors[0],
+                ],
+            ))
+        });
+        Ok(report)
+    }
+
+    // The entry's own project_id wins; the name lookup only covers entries the API sent without one
+    pub fn selected_entry_command(&self) -> Result<String, String> {
+        let language = self.config.language;
+        let (day, entry) = self
+            .selected_day()
+            .zip(self.selected_entry())
+            .ok_or_else(|| tr(language, Text::StatusNoEntrySelected).to_string())?;
+        let project_id = if entry.project_id != 0 {
+            entry.project_id
+        } else {
+            self.projects
+                .iter()
+                .find(|project| project.name == entry.project)
+                .map(|project| project.id)
+                .ok_or_else(|| {
+                    tr_args(language, Text::StatusProjectIdNotFound, &[&entry.project])
+                })?
+        };
+        let minutes = entry.minutes;
+
+        Ok(format!(
+            "vartui api create-entry --date {} --project-id {} --description {} --minutes {} --billable {}",
+            day.date,
+            project_id,
+            shell_quote(&entry.note),
+            minutes,
+            entry.billable
+        ))
+    }
+
+    pub fn refresh(&mut self) {
+        if !config_has_token(&self.config) {
+            self.rx = None;
+            self.rx_projects = None;
+            self.days_load = LoadState::Idle;
+            self.projects_load = LoadState::Idle;
+            self.open_config();
+            self.set_config_notice(tr(self.config.language, Text::StatusNoTokenPrompt).to_string());
+            return;
+        }
+
+        if let Some(remaining) = self.rate_limit_remaining() {
+            self.status = rate_limit_status(self.config.language, remaining);
+            return;
+        }
+
+        self.status = tr(self.config.language, Text::StatusRefreshing).to_string();
+        self.last_refresh = Instant::now();
+        if self.projects.is_empty() {
+            self.start_projects_load();
+        }
+        self.start_days_load();
+    }
+
+    fn hide_zero_minute_entries(&mut self, days: &mut [Day]) {
+        self.hidden_zero_entries = if self.config.hide_zero_minute_entries {
+            drop_zero_minute_entries(days)
+        } else {
+            0
+        };
+    }
+
+    // Re-derives day labels from cached entries without refetching the range
+    fn relabel_days_from_cache(&mut self) {
+        let Some((label, entries)) = &self.cached_entries else {
+            return;
+        };
+        if *label != self.date_range.label() {
+            return;
+        }
+
+        let mut days = build_days(
+            entries.clone(),
+            self.projects.clone(),
+            &self.date_range.start,
+            &self.date_range.end,
+        );
+        self.hide_zero_minute_entries(&mut days);
+        self.set_days(days);
+    }
+
+    pub fn show_toast(&mut self, message: String, kind: StatusKind) {
+        self.toast = Some((message, kind, Instant::now()));
+    }
+
+    // Ok/Err messages become the status line and a toast of the matching kind
+    fn toast_result(&mut self, result: Result<String, String>) {
+        let (message, kind) = match result {
+            Ok(message) => (message, StatusKind::Info),
+            Err(message) => (message, StatusKind::Error),
+        };
+        self.status = message.clone();
+        self.show_toast(message, kind);
+    }
+
+    // Called on every event-loop tick to expire transient UI state
+    pub fn tick(&mut self) {
+        if let Some((_, _, shown_at)) = &self.toast
+            && shown_at.elapsed() >= TOAST_DURATION
+        {
+            self.toast = None;
+        }
+        if let Some(since) = self.fresh_since
+            && since.elapsed() >= FRESH_HIGHLIGHT_DURATION
+        {
+            self.fresh_entries.clear();
+            self.fresh_since = None;
+        }
+        self.expire_rate_limit();
+        self.maybe_auto_refresh();
+        self.maybe_reload_config();
+        if self.theme_save_at.is_some_and(|due| Instant::now() >= due) {
+            self.flush_theme_save();
+        }
+    }
+
+    pub fn is_fresh_entry(&self, date: &str, entry: &Entry) -> bool {
+        self.fresh_entries
+            .iter()
+            .any(|(fresh_date, fresh)| fresh_date == date && fresh == entry)
+    }
+
+    pub fn rate_limit_remaining(&self) -> Option<Duration> {
+        self.rate_limited_until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    pub fn rate_limit_remaining_secs(&self) -> Option<u64> {
+        self.rate_limit_remaining()
+            .map(|remaining| remaining.as_secs_f32().ceil() as u64)
+    }
+
+    // Returns true when the error was a 429 and a cooldown was started
+    fn note_rate_limit(&mut self, error: &str) -> bool {
+        let Some(retry_after) = rate_limit_retry_after(error) else {
+            return false;
+        };
+        self.rate_limited_until = Some(Instant::now() + retry_after);
+        self.status = rate_limit_status(self.config.language, retry_after);
+        true
+    }
+
+    fn expire_rate_limit(&mut self) {
+        if self.rate_limited_until.is_none() || self.rate_limit_remaining().is_some() {
+            return;
+        }
+        self.rate_limited_until = None;
+        if std::mem::take(&mut self.retry_after_cooldown) && self.input_mode == InputMode::Normal {
+            self.refresh();
+        } else {
+            self.status = tr(self.config.language, Text::StatusRateLimitCleared).to_string();
+        }
+    }
+
+    // Only from the idle normal view, so modals and in-flight loads are never disrupted
+    fn maybe_auto_refresh(&mut self) {
+        let Some(secs) = self.config.auto_refresh_secs.filter(|secs| *secs > 0) else {
+            return;
+        };
+        if self.input_mode != InputMode::Normal
+            || self.rx.is_some()
+            || self.rate_limited_until.is_some()
+            || !config_has_token(&self.config)
+            || self.last_refresh.elapsed() < Duration::from_secs(secs)
+        {
+            return;
+        }
+        self.refresh();
+    }
+
+    // Picks up edits made to config.toml outside the TUI; an open config form wins
+    fn maybe_reload_config(&mut self) {
+        let Some(secs) = self.config.config_reload_secs.filter(|secs| *secs > 0) else {
+            return;
+        };
+        if self.config_form.is_some()
+            || self.theme_save_at.is_some()
+            || self.config_checked_at.elapsed() < Duration::from_secs(secs)
+        {
+            return;
+        }
+        self.config_checked_at = Instant::now();
+        let modified = config_modified();
+        if modified.is_none() || modified == self.config_mtime {
+            return;
+        }
+        self.config_mtime = modified;
+
+        let new_config = match try_load_config() {
+            Ok(config) => config,
+            Err(e) => {
+                log!("Config reload skipped: {}", e);
+                return;
+            }
+        };
+        let changes = describe_config_changes(&self.config, &new_config);
+        let range_changed = new_config.default_date_range != self.config.default_date_range;
+        let connection_changed = new_config.var_token != self.config.var_token
+            || new_config.base_url != self.config.base_url;
+        self.config = new_config;
+        self.days_split = self
+            .config
+            .days_split_percent
+            .clamp(DAYS_SPLIT_MIN, DAYS_SPLIT_MAX);
+
+        if range_changed
+            && let Some(range) = self
+                .config
+                .default_date_range
+                .as_deref()
+                .and_then(|range| parse_date_range(range).ok())
+        {
+            self.date_range = range;
+            self.set_days(build_empty_days(&self.date_range));
+        }
+        if range_changed || connection_changed {
+            self.refresh();
+        }
+        if !changes.is_empty() {
+            self.status = tr_args(
+                self.config.language,
+                Text::StatusConfigReloaded,
+                &[&changes.join("; ")],
+            );
+            self.show_toast(self.status.clone(), StatusKind::Info);
+        }
+    }
+
+    pub fn reload_projects(&mut self) {
+        if !config_has_token(&self.config) {
+            self.refresh();
+            return;
+        }
+
+        self.status = tr(self.config.language, Text::StatusRefreshingProjects).to_string();
+        self.start_projects_load();
+    }
+
+    pub fn start_input(&mut self) {
+        self.input_mode = InputMode::Editing;
+        self.input = format!("{}..{}", self.date_range.start, self.date_range.end);
+    }
+
+    pub fn cancel_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input.clear();
+    }
+
+    pub fn submit_input(&mut self) {
+        match parse_date_range(&self.input) {
+            Ok(range) => {
+                self.date_range = range;
+                self.input_mode = InputMode::Normal;
+                self.input.clear();
+                self.set_days(build_empty_days(&self.date_range));
+                self.refresh();
+            }
+            Err(error) => {
+                self.status = tr_args(self.config.language, Text::StatusRangeError, &[&error]);
+            }
+        }
+    }
+
+    pub fn input_push(&mut self, value: char) {
+        if value.is_ascii() && self.input.len() < INPUT_MAX_LEN {
+            self.input.push(value);
+        }
+    }
+
+    pub fn input_backspace(&mut self) {
+        self.input.pop();
+    }
+
+    // Global search across every day in the range
+    pub fn open_search(&mut self) {
+        self.input_mode = InputMode::Searching;
+        self.input.clear();
+        self.update_search_results();
+    }
+
+    pub fn close_search(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input.clear();
+        self.search_results.clear();
+    }
+
+    pub fn search_push(&mut self, value: char) {
+        if self.input.chars().count() < INPUT_MAX_LEN {
+            self.input.push(value);
+            self.update_search_results();
+        }
+    }
+
+    pub fn search_backspace(&mut self) {
+        self.input.pop();
+        self.update_search_results();
+    }
+
+    fn update_search_results(&mut self) {
+        let query = self.input.trim().to_lowercase();
+        self.search_results = if query.is_empty() {
+            Vec::new()
+        } else {
+            self.days
+                .iter()
+                .enumerate()
+                .flat_map(|(day_idx, day)| {
+                    day.entries
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, entry)| {
+                            entry.project.to_lowercase().contains(&query)
+                                || entry.note.to_lowercase().contains(&query)
+                        })
+                        .map(move |(entry_idx, _)| (day_idx, entry_idx))
+                })
+                .collect()
+        };
+        self.search_state.select(if self.search_results.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+    }
+
+    pub fn search_next(&mut self) {
+        let count = self.search_results.len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.search_state.selected() {
+            Some(idx) if idx + 1 < count => idx + 1,
+            _ => 0,
+        };
+        self.search_state.select(Some(next));
+    }
+
+    pub fn search_previous(&mut self) {
+        let count = self.search_results.len();
+        if count == 0 {
+            return;
+        }
+        let prev = match self.search_state.selected() {
+            Some(0) | None => count - 1,
+            Some(idx) => idx - 1,
+        };
+        self.search_state.select(Some(prev));
+    }
+
+    // Jumps to the containing day and selects the matched entry
+    pub fn apply_search_result(&mut self) {
+        let Some(&(day_idx, entry_idx)) = self
+            .search_state
+            .selected()
+            .and_then(|idx| self.search_results.get(idx))
+        else {
+            self.status = tr(self.config.language, Text::StatusNoResults).to_string();
+            return;
+        };
+        self.close_search();
+        self.select_entry_at(day_idx, entry_idx);
+    }
+
+    // Shows the entry even if the empty-day, billable or project filters would hide it
+    fn select_entry_at(&mut self, day_idx: usize, entry_idx: usize) {
+        if self.hide_empty_days && !self.visible_day_indices().contains(&day_idx) {
+            self.hide_empty_days = false;
+        }
+        self.day_state.select(Some(day_idx));
+        if let Some(entry) = self
+            .days
+            .get(day_idx)
+            .and_then(|day| day.entries.get(entry_idx))
+            && !self.billable_filter.matches(entry)
+        {
+            self.billable_filter = BillableFilter::All;
+        }
+        if let Some(entry) = self
+            .days
+            .get(day_idx)
+            .and_then(|day| day.entries.get(entry_idx))
+            && !self.matches_project_filter(entry)
+        {
+            self.project_filter = None;
+        }
+        self.focus = AppFocus::Entries;
+        self.entry_state.select(Some(entry_idx));
+    }
+
+    // Range bookmarks
+    pub fn bookmark_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.config.range_bookmarks.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn day_note(&self, date: &str) -> Option<&str> {
+        self.day_notes.get(date).map(String::as_str)
+    }
+
+    // An empty note removes the date from the file
+    pub fn set_day_note(&mut self, date: &str, note: &str) -> Result<(), String> {
+        let date = parse_date(date)
+            .ok_or_else(|| tr_args(self.config.language, Text::StatusInvalidDate, &[date]))?
+            .format("%Y-%m-%d")
+            .to_string();
+        let note = note.trim();
+        let mut notes = self.day_notes.clone();
+        if note.is_empty() {
+            notes.remove(&date);
+        } else {
+            notes.insert(date, note.chars().take(DAY_NOTE_MAX_LEN).collect());
+        }
+        save_day_notes(&notes)?;
+        self.day_notes = notes;
+        Ok(())
+    }
+
+    pub fn start_day_note(&mut self) {
+        let Some(day) = self.selected_day() else {
+            return;
+        };
+        self.input = self.day_note(&day.date).unwrap_or_default().to_string();
+        self.input_mode = InputMode::EditingDayNote;
+    }
+
+    pub fn day_note_input(&mut self, ch: char) {
+        push_capped(&mut self.input, ch, DAY_NOTE_MAX_LEN);
+    }
+
+    pub fn submit_day_note(&mut self) {
+        let Some(date) = self.selected_day().map(|day| day.date.clone()) else {
+            self.cancel_input();
+            return;
+        };
+        let note = self.input.clone();
+        match self.set_day_note(&date, &note) {
+            Ok(()) => {
+                let text = if note.trim().is_empty() {
+                    Text::StatusDayNoteCleared
+                } else {
+                    Text::StatusDayNoteSaved
+                };
+                self.status = tr_args(self.config.language, text, &[&date]);
+                self.input_mode = InputMode::Normal;
+                self.input.clear();
+            }
+            Err(error) => {
+                self.status = tr_args(self.config.language, Text::StatusDayNoteError, &[&error])
+            }
+        }
+    }
+
+    pub fn start_bookmark_name(&mut self) {
+        self.input_mode = InputMode::NamingBookmark;
+        self.input.clear();
+    }
+
+    pub fn submit_bookmark_name(&mut self) {
+        let name = self.input.trim().to_string();
+        match self.save_bookmark(&name) {
+            Ok(_) => {
+                self.input_mode = InputMode::Normal;
+                self.input.clear();
+            }
+            Err(error) => {
+                self.status = tr_args(self.config.language, Text::StatusBookmarkError, &[&error])
+            }
+        }
+    }
+
+    pub fn save_bookmark(&mut self, name: &str) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(tr(self.config.language, Text::StatusEmptyName).to_string());
+        }
+
+        let mut new_config = self.config.clone();
+        new_config
+            .range_bookmarks
+            .insert(name.to_string(), self.date_range.label());
+        save_config(&new_config)?;
+        self.config = new_config;
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusBookmarkSaved,
+            &[name, &self.date_range.label()],
+        );
+        Ok(())
+    }
+
+    pub fn open_bookmark_picker(&mut self) {
+        if self.config.range_bookmarks.is_empty() {
+            self.status = tr(self.config.language, Text::StatusNoBookmarks).to_string();
+            return;
+        }
+        self.bookmark_state.select(Some(0));
+        self.input_mode = InputMode::PickingBookmark;
+    }
+
+    pub fn close_bookmark_picker(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn bookmark_next(&mut self) {
+        let count = self.config.range_bookmarks.len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.bookmark_state.selected() {
+            Some(idx) if idx + 1 < count => idx + 1,
+            _ => 0,
+        };
+        self.bookmark_state.select(Some(next));
+    }
+
+    pub fn bookmark_previous(&mut self) {
+        let count = self.config.range_bookmarks.len();
+        if count == 0 {
+            return;
+        }
+        let prev = match self.bookmark_state.selected() {
+            Some(0) | None => count - 1,
+            Some(idx) => idx - 1,
+        };
+        self.bookmark_state.select(Some(prev));
+    }
+
+    pub fn apply_selected_bookmark(&mut self) {
+        let name = self
+            .bookmark_state
+            .selected()
+            .and_then(|idx| self.bookmark_names().get(idx).cloned());
+        self.input_mode = InputMode::Normal;
+        if let Some(name) = name
+            && let Err(error) = self.apply_bookmark(&name)
+        {
+            self.status = tr_args(self.config.language, Text::StatusBookmarkError, &[&error]);
+        }
+    }
+
+    pub fn apply_bookmark(&mut self, name: &str) -> Result<(), String> {
+        let range = self
+            .config
+            .range_bookmarks
+            .get(name)
+            .cloned()
+            .ok_or_else(|| tr_args(self.config.language, Text::StatusBookmarkNotFound, &[name]))?;
+        parse_date_range(&range).map_err(|error| {
+            tr_args(
+                self.config.language,
+                Text::StatusBookmarkInvalidRange,
+                &[&range, &error],
+            )
+        })?;
+
+        self.input = range;
+        self.submit_input();
+        Ok(())
+    }
+
+    // Entry templates
+    pub fn open_template_picker(&mut self) {
+        if self.config.entry_templates.is_empty() {
+            self.status = tr(self.config.language, Text::StatusNoTemplates).to_string();
+            return;
+        }
+        self.template_state.select(Some(0));
+        self.input_mode = InputMode::PickingTemplate;
+    }
+
+    pub fn close_template_picker(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub fn template_next(&mut self) {
+        let count = self.config.entry_templates.len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.template_state.selected() {
+            Some(idx) if idx + 1 < count => idx + 1,
+            _ => 0,
+        };
+        self.template_state.select(Some(next));
+    }
+
+    pub fn template_previous(&mut self) {
+        let count = self.config.entry_templates.len();
+        if count == 0 {
+            return;
+        }
+        let prev = match self.template_state.selected() {
+            Some(0) | None => count - 1,
+            Some(idx) => idx - 1,
+        };
+        self.template_state.select(Some(prev));
+    }
+
+    pub fn apply_selected_template(&mut self) {
+        let name = self
+            .template_state
+            .selected()
+            .and_then(|idx| self.config.entry_templates.get(idx))
+            .map(|template| template.name.clone());
+        self.input_mode = InputMode::Normal;
+        if let Some(name) = name
+            && let Err(error) = self.apply_template(&name)
+        {
+            self.status = tr_args(self.config.language, Text::StatusTemplateError, &[&error]);
+        }
+    }
+
+    // Opens the entry form for today prefilled from the template
+    pub fn apply_template(&mut self, name: &str) -> Result<(), String> {
+        let template = self
+            .config
+            .entry_templates
+            .iter()
+            .find(|template| template.name == name)
+            .cloned()
+            .ok_or_else(|| tr_args(self.config.language, Text::StatusTemplateNotFound, &[name]))?;
+        let project = self
+            .projects
+            .iter()
+            .find(|project| project.id == template.project_id)
+            .cloned();
+
+        let mut form = EntryForm::with_entry_data(
+            Local::now().format("%Y-%m-%d").to_string(),
+            project
+                .as_ref()
+                .map_or_else(|| template.project_id.to_string(), |p| p.name.clone()),
+            template.description.clone(),
+            template.minutes,
+            template.billable,
+        );
+        if template.minutes <= 0 {
+            form.minutes.clear();
+        }
+        form.selected_project = project;
+        self.entry_form = Some(form);
+        self.short_entry_confirmed = None;
+        self.input_mode = InputMode::AddingEntry;
+        self.update_project_filter();
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusTemplateApplied,
+            &[&template.name],
+        );
+        Ok(())
+    }
+
+    pub fn wait_background_load(&mut self, timeout: Duration) {
+        let deadline = Instant::now() + timeout;
+
+        if let Some(rx) = self.rx.take() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if let Ok(result) = rx.recv_timeout(remaining) {
+                self.apply_days_result(result);
             }
         }
 
         if let Some(rx) = self.rx_projects.take() {
             let remaining = deadline.saturating_duration_since(Instant::now());
-            match rx.recv_timeout(remaining) {
-                Ok(Ok(projects)) => {
-                    self.projects = projects;
-                    self.status = format!("proyectos cargados: {}", self.projects.len());
-                }
-                Ok(Err(e)) => {
-                    self.status = format!("error proyectos: {}", e);
-                }
-                Err(_) => {}
+            if let Ok(result) = rx.recv_timeout(remaining) {
+                self.apply_projects_result(result);
             }
         }
+        self.settle_startup_status();
     }
 
+    // Nothing to poll with synchronous_load: the receivers stay None
     pub fn check_background_load(&mut self) {
         let mut done = false;
         if let Some(rx) = &self.rx {
             match rx.try_recv() {
                 Ok(result) => {
-                    self.set_days(result.days);
-                    self.status = result.status;
+                    self.apply_days_result(result);
                     done = true;
                 }
                 Err(mpsc::TryRecvError::Empty) => {}
                 Err(_) => {
+                    self.days_load = LoadState::Failed(
+                        tr(self.config.language, Text::StatusLoadInterrupted).to_string(),
+                    );
                     done = true;
                 }
             }
@@ -518,17 +2173,15 @@ impl App {
         let mut done_projects = false;
         if let Some(rx) = &self.rx_projects {
             match rx.try_recv() {
-                Ok(Ok(projects)) => {
-                    self.projects = projects;
-                    self.status = format!("proyectos cargados: {}", self.projects.len());
-                    done_projects = true;
-                }
-                Ok(Err(e)) => {
-                    self.status = format!("error proyectos: {}", e);
+                Ok(result) => {
+                    self.apply_projects_result(result);
                     done_projects = true;
                 }
                 Err(mpsc::TryRecvError::Empty) => {}
                 Err(_) => {
+                    self.projects_load = LoadState::Failed(
+                        tr(self.config.language, Text::StatusLoadInterrupted).to_string(),
+                    );
                     done_projects = true;
                 }
             }
@@ -536,6 +2189,106 @@ impl App {
         if done_projects {
             self.rx_projects = None;
         }
+        self.settle_startup_status();
+    }
+
+    // Runs once both startup loads finish, and only replaces the loading status it owns
+    fn settle_startup_status(&mut self) {
+        if !self.startup_load || self.rx.is_some() || self.rx_projects.is_some() {
+            return;
+        }
+
+        self.startup_load = false;
+        let lang = self.config.language;
+        if self.status != tr(lang, Text::StatusLoading) {
+            return;
+        }
+        let days = self.days.len().to_string();
+        self.status = match (&self.days_load, &self.projects_load) {
+            (LoadState::Loaded, LoadState::Loaded) => tr_args(
+                lang,
+                Text::StatusReady,
+                &[&days, &self.projects.len().to_string()],
+            ),
+            (LoadState::Loaded, LoadState::Failed(error)) => {
+                tr_args(lang, Text::StatusReadyProjectsError, &[&days, error])
+            }
+            (LoadState::Failed(error), _) => error.clone(),
+            _ => return,
+        };
+    }
+
+    fn apply_days_result(&mut self, mut result: BackgroundResult) {
+        self.hide_zero_minute_entries(&mut result.days);
+        // Only a reload of already-loaded data has something meaningful to diff against
+        let changed = (result.ok && self.cached_entries.is_some())
+            .then(|| changed_day_count(&self.days, &result.days));
+        if changed.is_some() {
+            self.fresh_entries = fresh_entries(&self.days, &result.days);
+            self.fresh_since = (!self.fresh_entries.is_empty()).then(Instant::now);
+        }
+        self.days_load = if result.ok {
+            LoadState::Loaded
+        } else {
+            LoadState::Failed(result.status.clone())
+        };
+        self.cached_entries = if result.ok {
+            Some((result.range.label(), result.time_entries))
+        } else {
+            None
+        };
+        self.set_days(result.days);
+        if result.ok && self.startup_load && self.config.select_today_on_start {
+            self.select_today();
+        }
+        // The startup load reports one combined status once both requests finish
+        if !self.startup_load {
+            self.status = match changed {
+                Some(changed) => tr_args(
+                    self.config.language,
+                    Text::StatusChangedDays,
+                    &[&result.status, &changed.to_string()],
+                ),
+                None => result.status.clone(),
+            };
+        }
+        if !result.ok {
+            self.retry_after_cooldown |= self.note_rate_limit(&result.status);
+        }
+
+        // Projects may have arrived while the days fetch ran without them
+        if result.ok && !result.projects_resolved && !self.projects.is_empty() {
+            self.relabel_days_from_cache();
+        }
+    }
+
+    fn apply_projects_result(&mut self, result: Result<Vec<Project>, String>) {
+        match result {
+            Ok(projects) => {
+                if let Err(error) = replace_projects_cache(&projects) {
+                    log!("Could not update projects cache: {}", error);
+                }
+                self.projects = projects;
+                self.project_name_counts = project_name_counts(&self.projects);
+                self.projects_load = LoadState::Loaded;
+                self.update_project_filter();
+                if !self.startup_load {
+                    self.status = tr_args(
+                        self.config.language,
+                        Text::StatusProjectsLoaded,
+                        &[&self.projects.len().to_string()],
+                    );
+                }
+                self.relabel_days_from_cache();
+            }
+            Err(e) => {
+                if !self.startup_load {
+                    self.status = tr_args(self.config.language, Text::StatusProjectsError, &[&e]);
+                }
+                self.note_rate_limit(&e);
+                self.projects_load = LoadState::Failed(e);
+            }
+        }
     }
 
     pub fn open_add_entry(&mut self) {
@@ -544,13 +2297,20 @@ impl App {
         } else {
             Local::now().format("%Y-%m-%d").to_string()
         };
-        self.entry_form = Some(EntryForm::new(default_date));
+        self.open_add_entry_for(default_date);
+    }
+
+    pub fn open_add_entry_for(&mut self, date: String) {
+        self.entry_form = Some(EntryForm::new(date));
+        self.short_entry_confirmed = None;
         self.input_mode = InputMode::AddingEntry;
         self.update_project_filter();
     }
 
     pub fn close_add_entry(&mut self) {
         self.entry_form = None;
+        self.show_entry_preview = false;
+        self.short_entry_confirmed = None;
         self.input_mode = InputMode::Normal;
     }
 
@@ -560,6 +2320,14 @@ impl App {
         }
     }
 
+    pub fn form_focus_first_incomplete(&mut self) {
+        if let Some(form) = &mut self.entry_form
+            && !form.focus_first_incomplete()
+        {
+            self.status = tr(self.config.language, Text::StatusFormComplete).to_string();
+        }
+    }
+
     pub fn form_prev_field(&mut self) {
         if let Some(form) = &mut self.entry_form {
             form.prev_field();
@@ -567,15 +2335,23 @@ impl App {
     }
 
     pub fn form_input_push(&mut self, ch: char) {
+        let description_max = self.config.max_description_len;
         if let Some(form) = &mut self.entry_form {
             match form.focused {
-                FormField::Date => form.date.push(ch),
+                FormField::Date => {
+                    push_capped(&mut form.date, ch, FORM_DATE_MAX_LEN);
+                }
                 FormField::ProjectId => {
-                    form.project_search.push(ch);
-                    self.update_project_filter();
+                    if push_capped(&mut form.project_search, ch, INPUT_MAX_LEN) {
+                        self.update_project_filter();
+                    }
+                }
+                FormField::Description => {
+                    push_capped(&mut form.description, ch, description_max);
+                }
+                FormField::Minutes => {
+                    push_capped(&mut form.minutes, ch, FORM_MINUTES_MAX_LEN);
                 }
-                FormField::Description => form.description.push(ch),
-                FormField::Minutes => form.minutes.push(ch),
                 FormField::Billable => {
                     if ch == ' ' {
                         form.is_billable = !form.is_billable;
@@ -606,11 +2382,86 @@ impl App {
         }
     }
 
+    pub fn form_clear_project(&mut self) {
+        if let Some(form) = &mut self.entry_form {
+            form.selected_project = None;
+            form.project_search.clear();
+            form.focused = FormField::ProjectId;
+        }
+        self.update_project_filter();
+    }
+
+    // `slot` is 1-based to match the Alt+digit key
+    pub fn apply_minute_preset(&mut self, slot: usize) {
+        let Some(&minutes) = slot
+            .checked_sub(1)
+            .and_then(|index| self.config.minute_presets.get(index))
+        else {
+            return;
+        };
+        if let Some(form) = &mut self.entry_form
+            && form.focused == FormField::Minutes
+        {
+            form.minutes = minutes.to_string();
+        }
+    }
+
+    // Up/Down on a parseable minutes field; keeps the H:MM or plain format it was typed in
+    fn step_form_minutes(&mut self, direction: i32) -> bool {
+        let step = self.config.minutes_step as i32;
+        let Some(form) = &mut self.entry_form else {
+            return false;
+        };
+        if form.focused != FormField::Minutes {
+            return false;
+        }
+        let Some(current) = parse_minutes_strict(&form.minutes) else {
+            return true;
+        };
+        let minutes = (current + direction * step).max(0);
+        form.minutes = if form.minutes.contains(':') {
+            format!("{}:{:02}", minutes / 60, minutes % 60)
+        } else {
+            minutes.to_string()
+        };
+        true
+    }
+
+    pub fn projects_pending(&self) -> bool {
+        self.rx_projects.is_some() && self.projects.is_empty()
+    }
+
     pub fn update_project_filter(&mut self) {
         if let Some(form) = &mut self.entry_form {
             let query = form.project_search.to_lowercase();
+            form.recent_count = 0;
+            form.ambiguous_project = None;
             if query.is_empty() {
-                form.filtered_indices = (0..self.projects.len()).take(20).collect();
+                let recent: Vec<usize> = self
+                    .config
+                    .recent_project_ids
+                    .iter()
+                    .filter_map(|id| self.projects.iter().position(|p| p.id == *id))
+                    .collect();
+                form.recent_count = recent.len();
+                form.filtered_indices = recent
+                    .iter()
+                    .copied()
+                    .chain(
+                        (0..self.projects.len())
+                            .filter(|idx| !recent.contains(idx))
+                            .take(20),
+                    )
+                    .collect();
+            } else if is_project_id_query(&query) {
+                form.filtered_indices = self
+                    .projects
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, p)| p.id.to_string().starts_with(&query))
+                    .map(|(i, _)| i)
+                    .take(20)
+                    .collect();
             } else {
                 form.filtered_indices = self
                     .projects
@@ -630,23 +2481,31 @@ impl App {
     }
 
     pub fn form_nav_up(&mut self) {
-        if let Some(form) = &mut self.entry_form {
-            if form.focused == FormField::ProjectId && !form.filtered_indices.is_empty() {
-                let i = form.list_state.selected().unwrap_or(0);
-                if i > 0 {
-                    form.list_state.select(Some(i - 1));
-                }
+        if self.step_form_minutes(1) {
+            return;
+        }
+        if let Some(form) = &mut self.entry_form
+            && form.focused == FormField::ProjectId
+            && !form.filtered_indices.is_empty()
+        {
+            let i = form.list_state.selected().unwrap_or(0);
+            if i > 0 {
+                form.list_state.select(Some(i - 1));
             }
         }
     }
 
     pub fn form_nav_down(&mut self) {
-        if let Some(form) = &mut self.entry_form {
-            if form.focused == FormField::ProjectId && !form.filtered_indices.is_empty() {
-                let i = form.list_state.selected().unwrap_or(0);
-                if i + 1 < form.filtered_indices.len() {
-                    form.list_state.select(Some(i + 1));
-                }
+        if self.step_form_minutes(-1) {
+            return;
+        }
+        if let Some(form) = &mut self.entry_form
+            && form.focused == FormField::ProjectId
+            && !form.filtered_indices.is_empty()
+        {
+            let i = form.list_state.selected().unwrap_or(0);
+            if i + 1 < form.filtered_indices.len() {
+                form.list_state.select(Some(i + 1));
             }
         }
     }
@@ -658,30 +2517,177 @@ impl App {
 
         let is_project_focused = self.entry_form.as_ref().unwrap().focused == FormField::ProjectId;
         if is_project_focused {
+            let projects_pending = self.projects_pending();
             let form = self.entry_form.as_mut().unwrap();
-            if let Some(idx) = form.list_state.selected() {
-                if let Some(&project_idx) = form.filtered_indices.get(idx) {
-                    if let Some(project) = self.projects.get(project_idx) {
+            // All digits is an id lookup, not a name search
+            if form.selected_project.is_none() && is_project_id_query(&form.project_search) {
+                let id: i32 = form.project_search.parse().unwrap_or(0);
+                match self.projects.iter().find(|project| project.id == id) {
+                    Some(project) => {
                         form.selected_project = Some(project.clone());
+                        form.apply_billable_default(&self.config, project.id);
                         form.project_search = project.name.clone();
                         form.filtered_indices.clear();
                         form.next_field();
+                    }
+                    None if projects_pending => {
+                        self.status =
+                            tr(self.config.language, Text::StatusProjectsPending).to_string()
+                    }
+                    None => {
+                        self.status = tr_args(
+                            self.config.language,
+                            Text::StatusNoProjectWithId,
+                            &[&id.to_string()],
+                        )
+                    }
+                }
+                return;
+            }
+            if let Some(idx) = form.list_state.selected()
+                && let Some(&project_idx) = form.filtered_indices.get(idx)
+                && let Some(project) = self.projects.get(project_idx)
+            {
+                let same_name = self
+                    .project_name_counts
+                    .get(&project_name_key(&project.name))
+                    .copied()
+                    .unwrap_or(0);
+                if same_name > 1 {
+                    let client = tr_args(
+                        self.config.language,
+                        Text::ProjectOfClient,
+                        &[display_client(&self.config, &project.client_name)],
+                    );
+                    if form.ambiguous_project != Some(project.id) {
+                        form.ambiguous_project = Some(project.id);
+                        self.status = tr_args(
+                            self.config.language,
+                            Text::StatusAmbiguousProject,
+                            &[
+                                &same_name.to_string(),
+                                &project.name,
+                                &project.id.to_string(),
+                                &client,
+                            ],
+                        );
                         return;
                     }
+                    self.status = tr_args(
+                        self.config.language,
+                        Text::StatusProjectSelected,
+                        &[&project.id.to_string(), &client],
+                    );
                 }
+                form.selected_project = Some(project.clone());
+                form.apply_billable_default(&self.config, project.id);
+                form.project_search = project.name.clone();
+                form.filtered_indices.clear();
+                form.next_field();
+                return;
             }
         }
 
         let is_last = self.entry_form.as_ref().unwrap().focused == FormField::Billable;
-        if is_last {
+        if is_last && self.config.enter_submits_on_last {
             self.submit_entry();
         } else {
             self.form_next_field();
         }
     }
 
+    // Exact body `submit_entry` would POST for the current form, without validating it
+    pub fn entry_request(&self) -> Option<CreateEntryRequest> {
+        let form = self.entry_form.as_ref()?;
+        let project_id = if let Some(p) = &form.selected_project {
+            p.id
+        } else {
+            form.project_search.parse().unwrap_or(0)
+        };
+        Some(CreateEntryRequest {
+            date: form.date.clone(),
+            project_id,
+            description: form.description.clone(),
+            minutes: parse_minutes_input(&form.minutes),
+            is_billable: form.is_billable,
+            tag_ids: Vec::new(),
+        })
+    }
+
+    pub fn toggle_entry_preview(&mut self) {
+        if self.entry_form.is_some() {
+            self.show_entry_preview = !self.show_entry_preview;
+        }
+    }
+
     pub fn submit_entry(&mut self) {
-        let (d, p_id, desc, m_str, is_billable) = if let Some(form) = &self.entry_form {
+        if self.post_entry_form() {
+            self.close_add_entry();
+        }
+    }
+
+    pub fn retry_submit(&mut self) {
+        if !self
+            .entry_form
+            .as_ref()
+            .is_some_and(|form| form.submit_failed)
+        {
+            self.status = tr(self.config.language, Text::StatusNothingToRetry).to_string();
+            return;
+        }
+        self.submit_entry();
+    }
+
+    // Submits, then reopens a blank form on the same date and project
+    pub fn submit_and_continue(&mut self) {
+        let Some(form) = &self.entry_form else {
+            return;
+        };
+        let editing_id = form.editing_id;
+        let mut next = EntryForm::new(form.date.clone());
+        next.project_search = form.project_search.clone();
+        next.selected_project = form.selected_project.clone();
+        next.is_billable = form.is_billable;
+        next.focused = FormField::Description;
+
+        if !self.post_entry_form() {
+            return;
+        }
+        self.entry_form = Some(next);
+        self.show_entry_preview = false;
+        self.update_project_filter();
+        let lang = self.config.language;
+        self.status = match editing_id {
+            Some(id) => tr_args(lang, Text::StatusEntryUpdatedAddAnother, &[&id.to_string()]),
+            None => tr(lang, Text::StatusEntryCreatedAddAnother).to_string(),
+        };
+    }
+
+    // (minutes, threshold) when the form duration is positive but under warn_below_minutes
+    pub fn short_entry_warning(&self) -> Option<(i32, i32)> {
+        let threshold = self.config.warn_below_minutes?;
+        let minutes = parse_minutes_input(&self.entry_form.as_ref()?.minutes);
+        (minutes > 0 && minutes < threshold).then_some((minutes, threshold))
+    }
+
+    // Lets the next submit through without asking again
+    pub fn confirm_short_entry(&mut self) {
+        self.short_entry_confirmed = self.short_entry_warning().map(|(minutes, _)| minutes);
+    }
+
+    // Returns true once the API accepted the entry; the form is left untouched
+    fn post_entry_form(&mut self) -> bool {
+        // Without the list, project_search would be parsed as an id and usually post 0
+        if self.projects_pending()
+            && self
+                .entry_form
+                .as_ref()
+                .is_some_and(|form| form.selected_project.is_none())
+        {
+            self.status = tr(self.config.language, Text::StatusProjectsPending).to_string();
+            return false;
+        }
+        let (d, p_id, desc, m_str, is_billable, editing_id) = if let Some(form) = &self.entry_form {
             let pid = if let Some(p) = &form.selected_project {
                 p.id
             } else {
@@ -693,88 +2699,224 @@ impl App {
                 form.description.clone(),
                 form.minutes.clone(),
                 form.is_billable,
+                form.editing_id,
             )
         } else {
-            return;
+            return false;
         };
 
         if d.is_empty() || p_id == 0 || desc.is_empty() || m_str.is_empty() {
-            self.status = "error: campos vacios o proyecto invalido".to_string();
-            return;
+            self.status = tr(self.config.language, Text::StatusEmptyFields).to_string();
+            return false;
         }
 
-        let minutes: i32 = if m_str.contains(':') {
-            let parts: Vec<&str> = m_str.split(':').collect();
-            if parts.len() == 2 {
-                let h: i32 = parts[0].trim().parse().unwrap_or(0);
-                let m: i32 = parts[1].trim().parse().unwrap_or(0);
-                h * 60 + m
-            } else {
-                0
-            }
-        } else {
-            m_str.parse().unwrap_or(0)
-        };
+        let minutes = parse_minutes_input(&m_str);
 
         if minutes == 0 {
-            self.status = "error: tiempo invalido (0 o formato incorrecto)".to_string();
-            return;
+            self.status = tr(self.config.language, Text::StatusInvalidTime).to_string();
+            return false;
+        }
+
+        if let Some((minutes, threshold)) = self.short_entry_warning()
+            && self.short_entry_confirmed != Some(minutes)
+        {
+            self.short_entry_confirmed = Some(minutes);
+            self.status = tr_args(
+                self.config.language,
+                Text::StatusShortEntry,
+                &[&minutes.to_string(), &threshold.to_string()],
+            );
+            return false;
         }
+        self.short_entry_confirmed = None;
 
-        self.status = "creando registro...".to_string();
+        self.status = tr(self.config.language, Text::StatusCreating).to_string();
 
         let token = resolve_token(&self.config);
         if token.is_empty() {
             self.open_config();
-            self.status =
-                "No hay token configurado. Ingresa VAR Token y presiona Enter.".to_string();
-            return;
+            self.set_config_notice(tr(self.config.language, Text::StatusNoTokenPrompt).to_string());
+            return false;
         }
 
         let base_url = resolve_base_url(&self.config);
 
-        let client = match ApiClient::new(base_url, token) {
+        let client = match ApiClient::new(base_url, token).and_then(|client| {
+            client.with_create_endpoint(&self.config.create_method, &self.config.create_path)
+        }) {
             Ok(c) => c,
             Err(e) => {
-                self.status = format!("error cliente: {}", e);
-                return;
+                self.status = tr_args(self.config.language, Text::StatusClientError, &[&e]);
+                return false;
             }
         };
 
+        if let Some(id) = editing_id {
+            return match client.update_time_entry(id, &d, p_id, &desc, minutes, is_billable) {
+                Ok(()) => {
+                    self.status = tr_args(
+                        self.config.language,
+                        Text::StatusEntryUpdated,
+                        &[&id.to_string()],
+                    );
+                    self.show_toast(self.status.clone(), StatusKind::Info);
+                    self.refresh();
+                    true
+                }
+                Err(e) => {
+                    self.mark_submit_failed();
+                    self.status = tr_args(self.config.language, Text::StatusUpdateError, &[&e]);
+                    self.note_rate_limit(&e);
+                    self.show_toast(self.status.clone(), StatusKind::Error);
+                    false
+                }
+            };
+        }
+
         match client.create_time_entry(&d, p_id, &desc, minutes, is_billable) {
-            Ok(_) => {
-                self.close_add_entry();
-                self.status = "registro creado!".to_string();
+            Ok(created) => {
+                self.push_undo(created);
+                self.remember_recent_project(p_id);
+                self.status = tr(self.config.language, Text::StatusEntryCreated).to_string();
+                self.show_toast(self.status.clone(), StatusKind::Info);
                 self.refresh();
+                true
             }
             Err(e) => {
-                self.status = format!("error crear: {}", e);
+                self.mark_submit_failed();
+                self.status = tr_args(self.config.language, Text::StatusCreateError, &[&e]);
+                self.note_rate_limit(&e);
+                self.show_toast(self.status.clone(), StatusKind::Error);
+                false
+            }
+        }
+    }
+
+    fn mark_submit_failed(&mut self) {
+        if let Some(form) = &mut self.entry_form {
+            form.submit_failed = true;
+        }
+    }
+
+    // Backends that do not return the new id leave nothing to undo
+    fn push_undo(&mut self, created: Option<CreatedEntry>) {
+        let Some(created) = created else {
+            return;
+        };
+        self.undo_stack.push((
+            created.id,
+            format!(
+                "{} {} ({} min)",
+                created.date, created.description, created.minutes
+            ),
+        ));
+        if self.undo_stack.len() > UNDO_STACK_CAP {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    pub fn start_undo(&mut self) {
+        let Some((id, label)) = self.undo_stack.last() else {
+            self.status = tr(self.config.language, Text::StatusNothingToUndo).to_string();
+            return;
+        };
+        self.status = tr_args(
+            self.config.language,
+            Text::StatusUndoPrompt,
+            &[&id.to_string(), label],
+        );
+        self.input_mode = InputMode::ConfirmingUndo;
+        self.input.clear();
+    }
+
+    pub fn confirm_undo(&mut self) {
+        self.cancel_input();
+        let Some(&(id, _)) = self.undo_stack.last() else {
+            return;
+        };
+        match self.delete_entry(id) {
+            Ok(()) => {
+                self.undo_stack.pop();
+                self.status = tr_args(self.config.language, Text::StatusUndone, &[&id.to_string()]);
+                self.show_toast(self.status.clone(), StatusKind::Info);
+                self.refresh();
+            }
+            Err(error) => {
+                self.note_rate_limit(&error);
+                self.status = tr_args(self.config.language, Text::StatusUndoError, &[&error]);
+                self.show_toast(self.status.clone(), StatusKind::Error);
             }
         }
     }
 
+    fn delete_entry(&self, id: i32) -> Result<(), String> {
+        if let Some(remaining) = self.rate_limit_remaining() {
+            return Err(rate_limit_status(self.config.language, remaining));
+        }
+        let token = resolve_token(&self.config);
+        if token.is_empty() {
+            return Err(tr(self.config.language, Text::StatusNoTokenShort).to_string());
+        }
+        ApiClient::new(resolve_base_url(&self.config), token)?.delete_time_entry(id)
+    }
+
+    fn remember_recent_project(&mut self, project_id: i32) {
+        let mut recent = vec![project_id];
+        recent.extend(
+            self.config
+                .recent_project_ids
+                .iter()
+                .copied()
+                .filter(|id| *id != project_id),
+        );
+        recent.truncate(RECENT_PROJECTS_CAP);
+        if recent == self.config.recent_project_ids {
+            return;
+        }
+
+        let mut new_config = self.config.clone();
+        new_config.recent_project_ids = recent;
+        match save_config(&new_config) {
+            Ok(()) => self.config = new_config,
+            Err(e) => log!("Could not save recent projects: {}", e),
+        }
+    }
+
     // Config Modal Methods
     pub fn open_config(&mut self) {
         let theme = canonical_theme_slug(&self.config.theme).to_string();
         let mut theme_list_state = ListState::default();
         theme_list_state.select(Some(theme_index_for_query(&theme)));
+        let default_range = self.config.default_date_range.clone().unwrap_or_default();
+        let mut range_list_state = ListState::default();
+        range_list_state.select(Some(range_index_for_value(&default_range)));
 
         self.config_form = Some(ConfigForm {
             token: self.config.var_token.clone(),
             base_url: self.config.base_url.clone(),
-            default_range: self.config.default_date_range.clone().unwrap_or_default(),
+            default_range,
             theme,
             theme_list_state,
+            range_list_state,
             focused: ConfigField::Token,
+            notice: None,
         });
         self.input_mode = InputMode::Configuring;
-        self.status = "Configurando...".to_string();
+        self.status = tr(self.config.language, Text::StatusConfiguring).to_string();
+    }
+
+    // Status line plus the config modal footer, which does not show the status otherwise
+    fn set_config_notice(&mut self, message: String) {
+        if let Some(form) = &mut self.config_form {
+            form.notice = Some(message.clone());
+        }
+        self.status = message;
     }
 
     pub fn close_config(&mut self) {
         self.config_form = None;
         self.input_mode = InputMode::Normal;
-        self.status = "Cancelado".to_string();
+        self.status = tr(self.config.language, Text::StatusCancelled).to_string();
     }
 
     pub fn save_config_form(&mut self) {
@@ -794,23 +2936,42 @@ impl App {
 
             match save_config(&new_config) {
                 Ok(_) => {
+                    let changes = describe_config_changes(&self.config, &new_config);
+                    let range_changed =
+                        new_config.default_date_range != self.config.default_date_range;
                     self.config = new_config;
 
-                    // Apply new date range if set
-                    if let Some(range_str) = &self.config.default_date_range {
-                        if let Ok(r) = parse_date_range(range_str) {
-                            self.date_range = r;
-                            self.set_days(build_empty_days(&self.date_range));
-                        }
+                    // Only a changed default moves the view; saving other fields keeps the current range
+                    if range_changed
+                        && let Some(range_str) = &self.config.default_date_range
+                        && let Ok(r) = parse_date_range(range_str)
+                    {
+                        self.date_range = r;
+                        self.set_days(build_empty_days(&self.date_range));
                     }
 
-                    self.status = "Configuracion guardada!".to_string();
                     self.config_form = None;
                     self.input_mode = InputMode::Normal;
                     self.refresh();
+
+                    // Set after refresh so the summary isn't replaced by "actualizando..."
+                    self.status = if changes.is_empty() {
+                        tr(self.config.language, Text::StatusConfigSavedUnchanged).to_string()
+                    } else {
+                        tr_args(
+                            self.config.language,
+                            Text::StatusConfigSaved,
+                            &[&changes.join("; ")],
+                        )
+                    };
+                    self.show_toast(self.status.clone(), StatusKind::Info);
                 }
                 Err(e) => {
-                    self.status = format!("Error guardando: {}", e);
+                    self.set_config_notice(tr_args(
+                        self.config.language,
+                        Text::StatusConfigSaveError,
+                        &[&e],
+                    ));
                 }
             }
         }
@@ -842,12 +3003,22 @@ impl App {
         let mut should_sync_theme = false;
         if let Some(form) = &mut self.config_form {
             match form.focused {
-                ConfigField::Token => form.token.push(ch),
-                ConfigField::BaseUrl => form.base_url.push(ch),
-                ConfigField::DefaultRange => form.default_range.push(ch),
+                ConfigField::Token => {
+                    push_capped(&mut form.token, ch, TOKEN_MAX_LEN);
+                }
+                ConfigField::BaseUrl => {
+                    push_capped(&mut form.base_url, ch, BASE_URL_MAX_LEN);
+                }
+                ConfigField::DefaultRange => {
+                    // Typing over a keyword switches the dropdown to the custom text field
+                    if !form.is_custom_range() {
+                        form.default_range.clear();
+                        form.range_list_state.select(Some(RANGE_OPTIONS.len()));
+                    }
+                    push_capped(&mut form.default_range, ch, INPUT_MAX_LEN);
+                }
                 ConfigField::Theme => {
-                    form.theme.push(ch);
-                    should_sync_theme = true;
+                    should_sync_theme = push_capped(&mut form.theme, ch, INPUT_MAX_LEN);
                 }
             }
         }
@@ -868,7 +3039,9 @@ impl App {
                     form.base_url.pop();
                 }
                 ConfigField::DefaultRange => {
-                    form.default_range.pop();
+                    if form.is_custom_range() {
+                        form.default_range.pop();
+                    }
                 }
                 ConfigField::Theme => {
                     form.theme.pop();
@@ -887,7 +3060,10 @@ impl App {
             match form.focused {
                 ConfigField::Token => form.token.clear(),
                 ConfigField::BaseUrl => form.base_url.clear(),
-                ConfigField::DefaultRange => form.default_range.clear(),
+                ConfigField::DefaultRange => {
+                    form.default_range.clear();
+                    form.range_list_state.select(Some(RANGE_OPTIONS.len()));
+                }
                 ConfigField::Theme => {
                     form.theme.clear();
                     form.theme_list_state
@@ -904,6 +3080,78 @@ impl App {
         self.config_sync_theme_state();
     }
 
+    pub fn config_set_range_value(&mut self, value: String) {
+        if let Some(form) = &mut self.config_form {
+            form.range_list_state
+                .select(Some(range_index_for_value(&value)));
+            form.default_range = value;
+        }
+    }
+
+    pub fn config_use_current_range(&mut self) {
+        let label = self.date_range.label();
+        self.config_set_range_value(label.clone());
+        if let Some(form) = &mut self.config_form {
+            form.focused = ConfigField::DefaultRange;
+        }
+        self.set_config_notice(tr_args(
+            self.config.language,
+            Text::StatusDefaultFromView,
+            &[&label],
+        ));
+    }
+
+    pub fn config_nav_up(&mut self) {
+        match self.config_form.as_ref().map(|form| form.focused) {
+            Some(ConfigField::Theme) => self.config_theme_previous(),
+            Some(ConfigField::DefaultRange) => self.config_range_previous(),
+            _ => {}
+        }
+    }
+
+    pub fn config_nav_down(&mut self) {
+        match self.config_form.as_ref().map(|form| form.focused) {
+            Some(ConfigField::Theme) => self.config_theme_next(),
+            Some(ConfigField::DefaultRange) => self.config_range_next(),
+            _ => {}
+        }
+    }
+
+    pub fn config_range_next(&mut self) {
+        if let Some(form) = &mut self.config_form {
+            if form.focused != ConfigField::DefaultRange {
+                return;
+            }
+
+            let current = form
+                .range_list_state
+                .selected()
+                .unwrap_or_else(|| range_index_for_value(&form.default_range));
+            // Options plus the trailing custom entry
+            let next = (current + 1) % (RANGE_OPTIONS.len() + 1);
+            select_range_option(form, next);
+        }
+    }
+
+    pub fn config_range_previous(&mut self) {
+        if let Some(form) = &mut self.config_form {
+            if form.focused != ConfigField::DefaultRange {
+                return;
+            }
+
+            let current = form
+                .range_list_state
+                .selected()
+                .unwrap_or_else(|| range_index_for_value(&form.default_range));
+            let prev = if current == 0 {
+                RANGE_OPTIONS.len()
+            } else {
+                current - 1
+            };
+            select_range_option(form, prev);
+        }
+    }
+
     pub fn config_theme_next(&mut self) {
         if let Some(form) = &mut self.config_form {
             if form.focused != ConfigField::Theme || THEME_OPTIONS.is_empty() {
@@ -964,6 +3212,7 @@ impl App {
                     form.token.clear();
                     form.base_url = self.config.base_url.clone();
                     form.default_range.clear();
+                    form.range_list_state.select(Some(RANGE_OPTIONS.len()));
                     form.theme = self.config.theme.clone();
                     form.theme_list_state
                         .select(Some(theme_index_for_query(&form.theme)));
@@ -972,14 +3221,176 @@ impl App {
 
                 self.date_range = initial_date_range();
                 self.set_days(build_empty_days(&self.date_range));
-                self.status = "Configuracion restablecida".to_string();
+                self.set_config_notice(
+                    tr(self.config.language, Text::StatusConfigReset).to_string(),
+                );
                 self.refresh();
             }
             Err(e) => {
-                self.status = format!("Error restableciendo: {}", e);
+                self.set_config_notice(tr_args(
+                    self.config.language,
+                    Text::StatusConfigResetError,
+                    &[&e],
+                ));
+            }
+        }
+    }
+}
+
+// Worded in the new config's language, so switching language reports in the new one
+fn describe_config_changes(old: &AppConfig, new: &AppConfig) -> Vec<String> {
+    let language = new.language;
+    let mut changes = Vec::new();
+    if old.var_token != new.var_token {
+        changes.push(tr(language, Text::ChangeToken).to_string());
+    }
+    if old.base_url != new.base_url {
+        changes.push(tr_args(language, Text::ChangeBaseUrl, &[&new.base_url]));
+    }
+    if old.default_date_range != new.default_date_range {
+        match &new.default_date_range {
+            Some(range) => changes.push(tr_args(language, Text::ChangeRange, &[range])),
+            None => changes.push(tr(language, Text::ChangeRangeRemoved).to_string()),
+        }
+    }
+    if old.theme != new.theme {
+        changes.push(tr_args(language, Text::ChangeTheme, &[&new.theme]));
+    }
+    changes
+}
+
+fn range_index_for_value(value: &str) -> usize {
+    let normalized = match value.trim().to_ascii_uppercase().as_str() {
+        "MONTH" => "AUTO-MONTH".to_string(),
+        "WEEK" => "AUTO-WEEK".to_string(),
+        "QUARTER" => "AUTO-QUARTER".to_string(),
+        other => other.to_string(),
+    };
+
+    RANGE_OPTIONS
+        .iter()
+        .position(|option| *option == normalized)
+        .unwrap_or(RANGE_OPTIONS.len())
+}
+
+fn select_range_option(form: &mut ConfigForm, index: usize) {
+    let was_custom = form.is_custom_range();
+    form.range_list_state.select(Some(index));
+    match RANGE_OPTIONS.get(index) {
+        Some(option) => form.default_range = option.to_string(),
+        // Entering custom from a keyword starts with an empty range to type
+        None if !was_custom => form.default_range.clear(),
+        None => {}
+    }
+}
+
+pub fn is_project_id_query(query: &str) -> bool {
+    !query.is_empty() && query.chars().all(|c| c.is_ascii_digit())
+}
+
+// The API id when present; note and project otherwise
+fn entry_key(entry: &Entry) -> (i32, String, String) {
+    (entry.id, entry.project.clone(), entry.note.clone())
+}
+
+// Days in `new` that are missing from `old` or whose entries differ
+fn changed_day_count(old: &[Day], new: &[Day]) -> usize {
+    new.iter()
+        .filter(|day| !old.iter().any(|previous| previous == *day))
+        .count()
+}
+
+// Entries in `new` with no identical entry on the same date in `old`
+fn fresh_entries(old: &[Day], new: &[Day]) -> Vec<(String, Entry)> {
+    let mut fresh = Vec::new();
+    for day in new {
+        let previous = old.iter().find(|previous| previous.date == day.date);
+        for entry in &day.entries {
+            if !previous.is_some_and(|previous| previous.entries.contains(entry)) {
+                fresh.push((day.date.clone(), entry.clone()));
             }
         }
     }
+    fresh
+}
+
+// Appends unless the field already holds `max` chars; returns whether it grew
+fn push_capped(field: &mut String, ch: char, max: usize) -> bool {
+    if field.chars().count() >= max {
+        return false;
+    }
+    field.push(ch);
+    true
+}
+
+fn rate_limit_status(language: Language, remaining: Duration) -> String {
+    let secs = (remaining.as_secs_f32().ceil() as u64).to_string();
+    tr_args(language, Text::StatusRateLimited, &[&secs])
+}
+
+// Like `parse_minutes_input` but None for text that is not minutes or H:MM; empty is 0
+fn parse_minutes_strict(value: &str) -> Option<i32> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Some(0);
+    }
+    match value.split_once(':') {
+        Some((h, m)) => Some(h.trim().parse::<i32>().ok()? * 60 + m.trim().parse::<i32>().ok()?),
+        None => value.parse().ok(),
+    }
+}
+
+// Accepts plain minutes ("90") or H:MM ("1:30"); anything unparseable becomes 0
+pub fn parse_minutes_input(value: &str) -> i32 {
+    if value.contains(':') {
+        let parts: Vec<&str> = value.split(':').collect();
+        if parts.len() == 2 {
+            let h: i32 = parts[0].trim().parse().unwrap_or(0);
+            let m: i32 = parts[1].trim().parse().unwrap_or(0);
+            h * 60 + m
+        } else {
+            0
+        }
+    } else {
+        value.parse().unwrap_or(0)
+    }
+}
+
+// Problems the server would reject, surfaced in the payload preview
+pub fn entry_request_issues(language: Language, request: &CreateEntryRequest) -> Vec<String> {
+    let mut issues = Vec::new();
+    if request.date.is_empty() {
+        issues.push(Text::IssueEmptyDate);
+    }
+    if request.project_id == 0 {
+        issues.push(Text::IssueNoProject);
+    }
+    if request.description.is_empty() {
+        issues.push(Text::IssueEmptyDescription);
+    }
+    if request.minutes <= 0 {
+        issues.push(Text::IssueInvalidMinutes);
+    }
+    issues
+        .into_iter()
+        .map(|text| tr(language, text).to_string())
+        .collect()
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+fn project_name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn project_name_counts(projects: &[Project]) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for project in projects {
+        *counts.entry(project_name_key(&project.name)).or_insert(0) += 1;
+    }
+    counts
 }
 
 fn canonical_theme_slug(raw: &str) -> &'static str {
@@ -991,6 +3402,7 @@ fn canonical_theme_slug(raw: &str) -> &'static str {
         "catppuccin-light" | "latte" => "catppuccin-latte",
         "gruvbox" => "gruvbox-dark",
         "solarized" => "solarized-dark",
+        "contrast" | "hc" | "high-contrast-dark" => "high-contrast",
         other => other,
     };
 
@@ -1036,37 +3448,6 @@ fn theme_index_for_query(query: &str) -> usize {
         .unwrap_or(0)
 }
 
-fn resolve_token(config: &AppConfig) -> String {
-    if !config.var_token.is_empty() {
-        config.var_token.trim().to_string()
-    } else {
-        env::var("VAR_TOKEN")
-            .unwrap_or_default()
-            .replace('"', "")
-            .trim()
-            .to_string()
-    }
-}
-
-fn resolve_base_url(config: &AppConfig) -> String {
-    let mut base_url =
-        if !config.base_url.is_empty() && config.base_url != "https://var.elaniin.com/api" {
-            config.base_url.trim().to_string()
-        } else {
-            env::var("VAR_BASE_URL")
-                .ok()
-                .map(|v| v.replace('"', "").trim().to_string())
-                .filter(|v| !v.is_empty())
-                .unwrap_or_else(|| API_BASE.to_string())
-        };
-
-    if base_url.ends_with('/') {
-        base_url.pop();
-    }
-
-    base_url
-}
-
 fn config_has_token(config: &AppConfig) -> bool {
     !resolve_token(config).is_empty()
 }
@@ -1074,60 +3455,55 @@ fn config_has_token(config: &AppConfig) -> bool {
 // Background Task functions
 pub fn spawn_load(range: DateRange, config: &AppConfig) -> Receiver<BackgroundResult> {
     let (tx, rx) = mpsc::channel();
-    let token = resolve_token(config);
-    if token.is_empty() {
-        let _ = tx.send(BackgroundResult {
-            days: Vec::new(),
-            status: "No hay token configurado. Presiona c para configurar.".to_string(),
-        });
-        return rx;
-    }
-
-    let base_url = resolve_base_url(config);
-
+    let config = config.clone();
     thread::spawn(move || {
-        let result = match ApiClient::new(base_url, token) {
-            Ok(client) => match client.fetch_days(&range.start, &range.end) {
-                Ok(fetch_res) => {
-                    let count = fetch_res.days.len();
-                    BackgroundResult {
-                        days: fetch_res.days,
-                        status: format!("actualizado: {} dias", count),
-                    }
-                }
-                Err(e) => BackgroundResult {
-                    days: Vec::new(),
-                    status: e,
-                },
-            },
-            Err(e) => BackgroundResult {
-                days: Vec::new(),
-                status: e,
-            },
-        };
-        let _ = tx.send(result);
+        let _ = tx.send(load_days(range, &config));
     });
     rx
 }
 
 pub fn spawn_load_projects(config: &AppConfig) -> Receiver<Result<Vec<Project>, String>> {
     let (tx, rx) = mpsc::channel();
+    let config = config.clone();
+    thread::spawn(move || {
+        let _ = tx.send(load_projects(&config));
+    });
+    rx
+}
+
+fn load_days(range: DateRange, config: &AppConfig) -> BackgroundResult {
     let token = resolve_token(config);
     if token.is_empty() {
-        let _ = tx.send(Err(
-            "No hay token configurado. Presiona c para configurar.".to_string()
-        ));
-        return rx;
+        return BackgroundResult::failed(
+            range,
+            tr(config.language, Text::StatusNoTokenPressC).to_string(),
+        );
+    }
+
+    match ApiClient::new(resolve_base_url(config), token) {
+        Ok(client) => match client.fetch_days(&range.start, &range.end) {
+            Ok(fetch_res) => {
+                let count = fetch_res.days.len();
+                BackgroundResult {
+                    days: fetch_res.days,
+                    status: tr_args(config.language, Text::StatusUpdated, &[&count.to_string()]),
+                    ok: true,
+                    range,
+                    time_entries: fetch_res.time_entries,
+                    projects_resolved: fetch_res.projects_error.is_none(),
+                }
+            }
+            Err(e) => BackgroundResult::failed(range, e),
+        },
+        Err(e) => BackgroundResult::failed(range, e),
     }
+}
 
-    let base_url = resolve_base_url(config);
+fn load_projects(config: &AppConfig) -> Result<Vec<Project>, String> {
+    let token = resolve_token(config);
+    if token.is_empty() {
+        return Err(tr(config.language, Text::StatusNoTokenPressC).to_string());
+    }
 
-    thread::spawn(move || {
-        let result = match ApiClient::new(base_url, token) {
-            Ok(client) => client.fetch_projects_list().map_err(|e| e),
-            Err(e) => Err(e),
-        };
-        let _ = tx.send(result);
-    });
-    rx
+    ApiClient::new(resolve_base_url(config), token)?.fetch_projects_list()
 }
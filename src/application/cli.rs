@@ -1,26 +1,37 @@
 use std::env;
+use std::fs;
 
 use serde::Serialize;
+use serde_json::Value;
 
 use crate::domain::config::AppConfig;
-use crate::domain::models::{DateRange, Day};
-use crate::infrastructure::api_client::ApiClient;
-use crate::infrastructure::config::load_config;
-use crate::utils::parsing::parse_date_range;
+use crate::domain::models::{CreateEntryRequest, DateRange, Day};
+use crate::infrastructure::api_client::{ApiClient, FetchResult};
+use crate::infrastructure::config::{load_config, save_config};
+use crate::infrastructure::credentials::{DEFAULT_API_BASE, resolve_base_url, resolve_token};
+use crate::infrastructure::projects_cache::replace_projects_cache;
+use crate::ui::theme::canonical_theme_key;
+use crate::utils::ics::days_to_ics;
+use crate::utils::parsing::{drop_zero_minute_entries, parse_date_range};
+use crate::utils::timesheet::days_to_text;
 
-const DEFAULT_API_BASE: &str = "https://var.elaniin.com/api";
+const NO_CLIENT_LABEL: &str = "sin cliente";
 
-const API_HELP: &str = "  api projects [--pretty]\n  api days [--range <AUTO|AUTO-WEEK|AUTO-MONTH|YYYY-MM-DD..YYYY-MM-DD>] [--pretty]\n  api entries [--range <AUTO|AUTO-WEEK|AUTO-MONTH|YYYY-MM-DD..YYYY-MM-DD>] [--pretty]\n  api create-entry --date <YYYY-MM-DD> --project-id <ID> --description <TEXTO> --minutes <MINUTOS> [--billable <true|false>] [--pretty]";
+const API_HELP: &str = "  api projects [--raw] [--refresh-cache] [--pretty|--compact]\n  api days [--range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD>] [--billable <true|false>] [--include-empty <true|false>] [--raw] [--pretty|--compact]\n  api entries [--range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD>] [--billable <true|false>] [--project-id <ID>] [--group-by client] [--sort <hours|date|project>[:desc]] [--format <json|ics|text>] [--output <ARCHIVO>] [--raw] [--pretty|--compact]\n  api create-entry --date <YYYY-MM-DD> --project-id <ID> --description <TEXTO> --minutes <MINUTOS> [--billable <true|false>] [--pretty|--compact]\n  api create-entries <ARCHIVO> [--concurrency <N>] [--pretty|--compact]\n  api config export [--pretty|--compact]\n  api config import <ARCHIVO> [--pretty|--compact]\n  api config check [--fix] [--pretty|--compact]\n  api range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD> [--pretty|--compact]";
 
-const PROJECTS_HELP: &str = "Uso:\n  api projects [--pretty]";
+const PROJECTS_HELP: &str = "Uso:\n  api projects [--raw] [--refresh-cache] [--pretty|--compact]\n\n--refresh-cache descarga los proyectos, reemplaza el cache local y reporta altas/bajas.";
 
-const DAYS_HELP: &str =
-    "Uso:\n  api days [--range <AUTO|AUTO-WEEK|AUTO-MONTH|YYYY-MM-DD..YYYY-MM-DD>] [--pretty]";
+const DAYS_HELP: &str = "Uso:\n  api days [--range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD>] [--billable <true|false>] [--include-empty <true|false>] [--raw] [--pretty|--compact]";
 
-const ENTRIES_HELP: &str =
-    "Uso:\n  api entries [--range <AUTO|AUTO-WEEK|AUTO-MONTH|YYYY-MM-DD..YYYY-MM-DD>] [--pretty]";
+const ENTRIES_HELP: &str = "Uso:\n  api entries [--range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD>] [--billable <true|false>] [--project-id <ID>] [--group-by client] [--sort <hours|date|project>[:desc]] [--format <json|ics|text>] [--output <ARCHIVO>] [--raw] [--pretty|--compact]";
 
-const CREATE_ENTRY_HELP: &str = "Uso:\n  api create-entry --date <YYYY-MM-DD> --project-id <ID> --description <TEXTO> --minutes <MINUTOS> [--billable <true|false>] [--pretty]";
+const CREATE_ENTRY_HELP: &str = "Uso:\n  api create-entry --date <YYYY-MM-DD> --project-id <ID> --description <TEXTO> --minutes <MINUTOS> [--billable <true|false>] [--pretty|--compact]";
+
+const CREATE_ENTRIES_HELP: &str = "Uso:\n  api create-entries <ARCHIVO> [--concurrency <N>] [--pretty|--compact]\n\nARCHIVO es un arreglo JSON de {date, project_id, description, minutes, is_billable?}. La concurrencia default viene de bulk_concurrency (3).";
+
+const CONFIG_HELP: &str = "Uso:\n  api config export [--pretty|--compact]\n  api config import <ARCHIVO> [--pretty|--compact]\n  api config check [--fix] [--pretty|--compact]\n\nexport omite var_token; import conserva el token guardado salvo que el archivo traiga uno.\ncheck valida base_url, theme y default_date_range y muestra la config normalizada; --fix la guarda.";
+
+const RANGE_HELP: &str = "Uso:\n  api range <AUTO|AUTO-WEEK|AUTO-MONTH|AUTO-QUARTER|YYYY-MM-DD..YYYY-MM-DD> [--pretty|--compact]\n\nResuelve el rango como lo haria vartui, sin llamar al API.";
 
 #[derive(Serialize)]
 struct ProjectOutput {
@@ -29,29 +40,99 @@ struct ProjectOutput {
     client_name: String,
 }
 
+#[derive(Serialize)]
+struct CacheRefreshOutput {
+    ok: bool,
+    total: usize,
+    added: usize,
+    removed: usize,
+}
+
 #[derive(Serialize)]
 struct DaysOutput {
     range: String,
     days: Vec<Day>,
+    #[serde(skip_serializing_if = "is_zero")]
+    hidden_zero_minute: usize,
 }
 
 #[derive(Serialize)]
 struct EntryOutput {
     date: String,
     project: String,
+    minutes: i32,
     hours: f32,
     note: String,
+    billable: bool,
+    client: String,
 }
 
 #[derive(Serialize)]
 struct EntriesOutput {
     range: String,
     entries: Vec<EntryOutput>,
+    #[serde(skip_serializing_if = "is_zero")]
+    hidden_zero_minute: usize,
+}
+
+#[derive(Serialize)]
+struct ClientGroupOutput {
+    client: String,
+    total_hours: f32,
+    entries: Vec<EntryOutput>,
+}
+
+#[derive(Serialize)]
+struct ClientEntriesOutput {
+    range: String,
+    clients: Vec<ClientGroupOutput>,
+    #[serde(skip_serializing_if = "is_zero")]
+    hidden_zero_minute: usize,
+}
+
+#[derive(Serialize)]
+struct BulkCreateError {
+    index: usize,
+    date: String,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct BulkCreateOutput {
+    ok: bool,
+    created: usize,
+    failed: usize,
+    errors: Vec<BulkCreateError>,
+}
+
+#[derive(Serialize)]
+struct ConfigImportOutput {
+    ok: bool,
+    updated: Vec<String>,
+    // Keys AppConfig does not have; left out of the saved config
+    ignored: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RangeOutput {
+    start: String,
+    end: String,
+    label: String,
+}
+
+#[derive(Serialize)]
+struct ConfigCheckOutput {
+    ok: bool,
+    problems: Vec<String>,
+    fixed: bool,
+    normalized: Value,
 }
 
 #[derive(Serialize)]
 struct CreateEntryOutput {
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<i32>,
     date: String,
     project_id: i32,
     minutes: i32,
@@ -69,6 +150,9 @@ pub fn run_api(args: &[String]) -> Result<(), String> {
         "days" => cmd_days(&args[1..]),
         "entries" => cmd_entries(&args[1..]),
         "create-entry" => cmd_create_entry(&args[1..]),
+        "create-entries" => cmd_create_entries(&args[1..]),
+        "config" => cmd_config(&args[1..]),
+        "range" => cmd_range(&args[1..]),
         other => Err(format!("Comando API desconocido: {other}\n\n{API_HELP}")),
     }
 }
@@ -83,9 +167,46 @@ fn cmd_projects(args: &[String]) -> Result<(), String> {
         return Ok(());
     }
 
-    let pretty = parse_pretty_flag(args)?;
+    let mut pretty = default_pretty();
+    let mut raw = false;
+    let mut refresh_cache = false;
+    for arg in args {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            "--raw" => raw = true,
+            "--refresh-cache" => refresh_cache = true,
+            unknown => {
+                return Err(format!("Flag desconocida: {unknown}\n\n{PROJECTS_HELP}"));
+            }
+        }
+    }
+    if raw && refresh_cache {
+        return Err(format!(
+            "--raw no se puede combinar con --refresh-cache\n\n{PROJECTS_HELP}"
+        ));
+    }
+
     let (_, client) = build_client_and_config()?;
+    if raw {
+        println!("{}", client.fetch_raw("projects", &[])?);
+        return Ok(());
+    }
+
     let projects = client.fetch_projects_list()?;
+    if refresh_cache {
+        let diff = replace_projects_cache(&projects)?;
+        return print_json(
+            &CacheRefreshOutput {
+                ok: true,
+                total: projects.len(),
+                added: diff.added,
+                removed: diff.removed,
+            },
+            pretty,
+        );
+    }
+
     let output: Vec<ProjectOutput> = projects
         .into_iter()
         .map(|project| ProjectOutput {
@@ -105,17 +226,56 @@ fn cmd_days(args: &[String]) -> Result<(), String> {
     }
 
     let options = parse_list_options(args, DAYS_HELP)?;
+    if options.group_by_client {
+        return Err(format!("--group-by solo aplica a entries\n\n{DAYS_HELP}"));
+    }
+    if options.sort.is_some() || options.project_id.is_some() {
+        return Err(format!(
+            "--sort y --project-id solo aplican a entries\n\n{DAYS_HELP}"
+        ));
+    }
+    if options.format != ListFormat::Json || options.output.is_some() {
+        return Err(format!(
+            "--format y --output solo aplican a entries\n\n{DAYS_HELP}"
+        ));
+    }
     let (config, client) = build_client_and_config()?;
     let range = resolve_range(options.range, &config)?;
-    let fetch = client.fetch_days(&range.start, &range.end)?;
+    if options.raw {
+        return print_raw_time_entries(&client, &range);
+    }
+    let mut fetch = fetch_days_with_projects(&client, &range)?;
+    let hidden_zero_minute = if config.hide_zero_minute_entries {
+        drop_zero_minute_entries(&mut fetch.days)
+    } else {
+        0
+    };
+    if let Some(billable) = options.billable {
+        for day in &mut fetch.days {
+            day.entries.retain(|entry| entry.billable == billable);
+        }
+    }
+    if !options.include_empty {
+        fetch.days.retain(|day| day.total_hours() > 0.0);
+    }
     let output = DaysOutput {
         range: range.label(),
         days: fetch.days,
+        hidden_zero_minute,
     };
 
     print_json(&output, options.pretty)
 }
 
+// The TUI can relabel later; one-shot output with "Proyecto {id}" placeholders would be wrong
+fn fetch_days_with_projects(client: &ApiClient, range: &DateRange) -> Result<FetchResult, String> {
+    let fetch = client.fetch_days(&range.start, &range.end)?;
+    match fetch.projects_error {
+        Some(error) => Err(format!("No se pudieron cargar los proyectos: {error}")),
+        None => Ok(fetch),
+    }
+}
+
 fn cmd_entries(args: &[String]) -> Result<(), String> {
     if contains_help(args) {
         println!("{ENTRIES_HELP}");
@@ -123,30 +283,147 @@ fn cmd_entries(args: &[String]) -> Result<(), String> {
     }
 
     let options = parse_list_options(args, ENTRIES_HELP)?;
+    if options.format != ListFormat::Json && (options.raw || options.group_by_client) {
+        return Err(format!(
+            "--format ics|text no se combina con --raw ni --group-by\n\n{ENTRIES_HELP}"
+        ));
+    }
+    if options.sort.is_some()
+        && (options.raw || options.group_by_client || options.format != ListFormat::Json)
+    {
+        return Err(format!(
+            "--sort no se combina con --raw, --group-by ni --format ics|text\n\n{ENTRIES_HELP}"
+        ));
+    }
+    if options.output.is_some() && options.format == ListFormat::Json {
+        return Err(format!(
+            "--output requiere --format ics|text\n\n{ENTRIES_HELP}"
+        ));
+    }
     let (config, client) = build_client_and_config()?;
     let range = resolve_range(options.range, &config)?;
-    let fetch = client.fetch_days(&range.start, &range.end)?;
+    if options.raw {
+        return print_raw_time_entries(&client, &range);
+    }
+    let mut fetch = fetch_days_with_projects(&client, &range)?;
+    let hidden_zero_minute = if config.hide_zero_minute_entries {
+        drop_zero_minute_entries(&mut fetch.days)
+    } else {
+        0
+    };
+    if let Some(project_id) = options.project_id {
+        for day in &mut fetch.days {
+            day.entries.retain(|entry| entry.project_id == project_id);
+        }
+    }
+
+    if options.format != ListFormat::Json {
+        if let Some(billable) = options.billable {
+            for day in &mut fetch.days {
+                day.entries.retain(|entry| entry.billable == billable);
+            }
+        }
+        let document = if options.format == ListFormat::Ics {
+            days_to_ics(&fetch.days)
+        } else {
+            days_to_text(&fetch.days)
+        };
+        return match options.output {
+            Some(path) => fs::write(&path, document)
+                .map_err(|error| format!("No se pudo escribir {path}: {error}")),
+            None => {
+                print!("{document}");
+                Ok(())
+            }
+        };
+    }
 
     let mut entries = Vec::new();
     for day in fetch.days {
         for entry in day.entries {
+            if options
+                .billable
+                .is_some_and(|billable| entry.billable != billable)
+            {
+                continue;
+            }
             entries.push(EntryOutput {
                 date: day.date.clone(),
                 project: entry.project,
+                minutes: entry.minutes,
                 hours: entry.hours,
                 note: entry.note,
+                billable: entry.billable,
+                client: entry.client,
             });
         }
     }
 
+    if let Some(sort) = options.sort {
+        sort_entries(&mut entries, sort);
+    }
+
+    if options.group_by_client {
+        let output = ClientEntriesOutput {
+            range: range.label(),
+            clients: group_entries_by_client(entries),
+            hidden_zero_minute,
+        };
+        return print_json(&output, options.pretty);
+    }
+
     let output = EntriesOutput {
         range: range.label(),
         entries,
+        hidden_zero_minute,
     };
 
     print_json(&output, options.pretty)
 }
 
+// Stable, so entries that tie keep the day-then-insertion order
+fn sort_entries(entries: &mut [EntryOutput], sort: EntrySort) {
+    entries.sort_by(|a, b| {
+        let ordering = match sort.field {
+            SortField::Hours => a.hours.total_cmp(&b.hours),
+            SortField::Date => a.date.cmp(&b.date),
+            SortField::Project => a.project.to_lowercase().cmp(&b.project.to_lowercase()),
+        };
+        if sort.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+// Clients alphabetically (unknown client last), entries by date inside each group
+fn group_entries_by_client(entries: Vec<EntryOutput>) -> Vec<ClientGroupOutput> {
+    let mut groups: Vec<ClientGroupOutput> = Vec::new();
+    for entry in entries {
+        let client = if entry.client.trim().is_empty() {
+            NO_CLIENT_LABEL.to_string()
+        } else {
+            entry.client.clone()
+        };
+        match groups.iter_mut().find(|group| group.client == client) {
+            Some(group) => group.entries.push(entry),
+            None => groups.push(ClientGroupOutput {
+                client,
+                total_hours: 0.0,
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    for group in &mut groups {
+        group.entries.sort_by(|a, b| a.date.cmp(&b.date));
+        group.total_hours = group.entries.iter().map(|entry| entry.hours).sum();
+    }
+    groups.sort_by_key(|group| (group.client == NO_CLIENT_LABEL, group.client.to_lowercase()));
+    groups
+}
+
 fn cmd_create_entry(args: &[String]) -> Result<(), String> {
     if contains_help(args) {
         println!("{CREATE_ENTRY_HELP}");
@@ -158,7 +435,7 @@ fn cmd_create_entry(args: &[String]) -> Result<(), String> {
     let mut description: Option<String> = None;
     let mut minutes: Option<i32> = None;
     let mut is_billable = true;
-    let mut pretty = false;
+    let mut pretty = default_pretty();
 
     let mut i = 0usize;
     while i < args.len() {
@@ -202,6 +479,7 @@ fn cmd_create_entry(args: &[String]) -> Result<(), String> {
                     .ok_or_else(|| format!("Valor invalido para --billable: {value}"))?;
             }
             "--pretty" => pretty = true,
+            "--compact" => pretty = false,
             unknown => {
                 return Err(format!(
                     "Flag desconocida para create-entry: {unknown}\n\n{CREATE_ENTRY_HELP}"
@@ -216,58 +494,336 @@ fn cmd_create_entry(args: &[String]) -> Result<(), String> {
     let description = description.ok_or_else(|| "Falta --description".to_string())?;
     let minutes = minutes.ok_or_else(|| "Falta --minutes".to_string())?;
 
-    let (_, client) = build_client_and_config()?;
-    client.create_time_entry(&date, project_id, &description, minutes, is_billable)?;
+    let (config, client) = build_client_and_config()?;
+    let client = client.with_create_endpoint(&config.create_method, &config.create_path)?;
+    let created =
+        client.create_time_entry(&date, project_id, &description, minutes, is_billable)?;
 
-    let output = CreateEntryOutput {
-        ok: true,
-        date,
-        project_id,
-        minutes,
-        is_billable,
+    // Prefer what the backend stored over what was sent
+    let output = match created {
+        Some(created) => CreateEntryOutput {
+            ok: true,
+            id: Some(created.id),
+            date: created.date,
+            project_id: created.project_id,
+            minutes: created.minutes,
+            is_billable: created.is_billable,
+        },
+        None => CreateEntryOutput {
+            ok: true,
+            id: None,
+            date,
+            project_id,
+            minutes,
+            is_billable,
+        },
     };
 
     print_json(&output, pretty)
 }
 
-fn build_client_and_config() -> Result<(AppConfig, ApiClient), String> {
+fn cmd_create_entries(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || contains_help(args) {
+        println!("{CREATE_ENTRIES_HELP}");
+        return Ok(());
+    }
+
+    let mut path: Option<String> = None;
+    let mut concurrency: Option<usize> = None;
+    let mut pretty = default_pretty();
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--concurrency" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --concurrency")?;
+                let parsed = value
+                    .parse::<usize>()
+                    .map_err(|_| format!("concurrency invalido: {value}"))?;
+                if parsed == 0 {
+                    return Err("concurrency debe ser mayor a 0".to_string());
+                }
+                concurrency = Some(parsed);
+            }
+            "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            value if path.is_none() && !value.starts_with("--") => path = Some(value.to_string()),
+            unknown => {
+                return Err(format!(
+                    "Flag desconocida para create-entries: {unknown}\n\n{CREATE_ENTRIES_HELP}"
+                ));
+            }
+        }
+        i += 1;
+    }
+
+    let path = path.ok_or_else(|| format!("Falta <ARCHIVO>\n\n{CREATE_ENTRIES_HELP}"))?;
+    let raw =
+        fs::read_to_string(&path).map_err(|error| format!("No se pudo leer {path}: {error}"))?;
+    let requests: Vec<CreateEntryRequest> =
+        serde_json::from_str(&raw).map_err(|error| format!("JSON invalido en {path}: {error}"))?;
+
+    let (config, client) = build_client_and_config()?;
+    let client = client.with_create_endpoint(&config.create_method, &config.create_path)?;
+    let concurrency = concurrency.unwrap_or(config.bulk_concurrency);
+    let results = client.create_time_entries(&requests, concurrency);
+
+    let errors: Vec<BulkCreateError> = results
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, result)| {
+            result.err().map(|error| BulkCreateError {
+                index,
+                date: requests[index].date.clone(),
+                error,
+            })
+        })
+        .collect();
+    let output = BulkCreateOutput {
+        ok: errors.is_empty(),
+        created: requests.len() - errors.len(),
+        failed: errors.len(),
+        errors,
+    };
+
+    print_json(&output, pretty)
+}
+
+fn cmd_range(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || contains_help(args) {
+        println!("{RANGE_HELP}");
+        return Ok(());
+    }
+
+    let mut raw: Option<String> = None;
+    let mut pretty = default_pretty();
+    for arg in args {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            value if raw.is_none() && !value.starts_with("--") => raw = Some(value.to_string()),
+            unknown => {
+                return Err(format!("Flag desconocida: {unknown}\n\n{RANGE_HELP}"));
+            }
+        }
+    }
+    let raw = raw.ok_or_else(|| format!("Falta el rango\n\n{RANGE_HELP}"))?;
+
+    let range =
+        parse_date_range(&raw).map_err(|error| format!("Rango invalido ({raw}): {error}"))?;
+    let output = RangeOutput {
+        label: range.label(),
+        start: range.start,
+        end: range.end,
+    };
+    print_json(&output, pretty)
+}
+
+fn cmd_config(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || contains_help(args) {
+        println!("{CONFIG_HELP}");
+        return Ok(());
+    }
+
+    match args[0].as_str() {
+        "export" => cmd_config_export(&args[1..]),
+        "import" => cmd_config_import(&args[1..]),
+        "check" => cmd_config_check(&args[1..]),
+        other => Err(format!(
+            "Subcomando config desconocido: {other}\n\n{CONFIG_HELP}"
+        )),
+    }
+}
+
+fn cmd_config_export(args: &[String]) -> Result<(), String> {
+    let pretty = parse_pretty_flag(args, CONFIG_HELP)?;
     let config = load_config();
+    let mut value = serde_json::to_value(&config).map_err(|error| error.to_string())?;
+    if let Some(map) = value.as_object_mut() {
+        map.remove("var_token");
+    }
 
-    let token = if !config.var_token.is_empty() {
-        config.var_token.trim().to_string()
-    } else {
-        env::var("VAR_TOKEN")
-            .unwrap_or_default()
-            .replace('"', "")
-            .trim()
-            .to_string()
+    print_json(&value, pretty)
+}
+
+fn cmd_config_import(args: &[String]) -> Result<(), String> {
+    let mut path: Option<String> = None;
+    let mut pretty = default_pretty();
+    for arg in args {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            value if path.is_none() && !value.starts_with("--") => path = Some(value.to_string()),
+            unknown => {
+                return Err(format!("Flag desconocida: {unknown}\n\n{CONFIG_HELP}"));
+            }
+        }
+    }
+    let path = path.ok_or_else(|| format!("Falta archivo a importar\n\n{CONFIG_HELP}"))?;
+
+    let raw =
+        fs::read_to_string(&path).map_err(|error| format!("No se pudo leer {path}: {error}"))?;
+    let incoming: Value =
+        serde_json::from_str(&raw).map_err(|error| format!("json invalido en {path}: {error}"))?;
+    let incoming = incoming
+        .as_object()
+        .ok_or_else(|| format!("{path} debe contener un objeto JSON"))?;
+
+    let current = load_config();
+    let mut merged = serde_json::to_value(&current).map_err(|error| error.to_string())?;
+    let mut updated = Vec::new();
+    let mut ignored = Vec::new();
+    if let Some(map) = merged.as_object_mut() {
+        for (key, value) in incoming {
+            if !map.contains_key(key) {
+                ignored.push(key.clone());
+                continue;
+            }
+            let is_empty_token =
+                key == "var_token" && value.as_str().is_none_or(|token| token.trim().is_empty());
+            if is_empty_token {
+                continue;
+            }
+            map.insert(key.clone(), value.clone());
+            updated.push(key.clone());
+        }
+    }
+
+    let config: AppConfig = serde_json::from_value(merged)
+        .map_err(|error| format!("Configuracion invalida en {path}: {error}"))?;
+    save_config(&config)?;
+
+    let output = ConfigImportOutput {
+        ok: true,
+        updated,
+        ignored,
     };
+    print_json(&output, pretty)
+}
 
-    if token.is_empty() {
-        return Err(
-            "No hay token configurado. Define VAR_TOKEN o guarda var_token en la configuracion."
-                .to_string(),
-        );
+fn cmd_config_check(args: &[String]) -> Result<(), String> {
+    let mut pretty = default_pretty();
+    let mut fix = false;
+    for arg in args {
+        match arg.as_str() {
+            "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            "--fix" => fix = true,
+            unknown => {
+                return Err(format!("Flag desconocida: {unknown}\n\n{CONFIG_HELP}"));
+            }
+        }
     }
 
-    let mut base_url = if !config.base_url.is_empty() && config.base_url != DEFAULT_API_BASE {
-        config.base_url.trim().to_string()
-    } else {
-        env::var("VAR_BASE_URL")
-            .ok()
-            .map(|value| value.replace('"', "").trim().to_string())
-            .filter(|value| !value.is_empty())
-            .unwrap_or_else(|| DEFAULT_API_BASE.to_string())
+    let current = load_config();
+    let (normalized, problems) = normalize_config(&current);
+    let fixed = fix && !problems.is_empty();
+    if fixed {
+        save_config(&normalized)?;
+    }
+
+    let mut value = serde_json::to_value(&normalized).map_err(|error| error.to_string())?;
+    if let Some(map) = value.as_object_mut() {
+        map.remove("var_token");
+    }
+    let output = ConfigCheckOutput {
+        ok: problems.is_empty(),
+        problems,
+        fixed,
+        normalized: value,
     };
+    print_json(&output, pretty)
+}
+
+// Returns the config with fixable values cleaned up, plus one message per problem found
+fn normalize_config(config: &AppConfig) -> (AppConfig, Vec<String>) {
+    let mut normalized = config.clone();
+    let mut problems = Vec::new();
 
-    if base_url.ends_with('/') {
-        base_url.pop();
+    let base_url = config.base_url.trim().trim_end_matches('/');
+    if base_url.is_empty() {
+        problems.push(format!("base_url vacio; se usa {DEFAULT_API_BASE}"));
+        normalized.base_url = DEFAULT_API_BASE.to_string();
+    } else {
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            problems.push(format!(
+                "base_url debe empezar con http:// o https:// ({base_url})"
+            ));
+        }
+        if base_url != config.base_url {
+            problems.push(format!(
+                "base_url tiene espacios o '/' final: {:?} -> {base_url:?}",
+                config.base_url
+            ));
+        }
+        normalized.base_url = base_url.to_string();
+    }
+
+    match canonical_theme_key(&config.theme) {
+        Some(theme) => {
+            if theme != config.theme {
+                problems.push(format!("theme {:?} se normaliza a {theme}", config.theme));
+            }
+            normalized.theme = theme;
+        }
+        None => {
+            problems.push(format!(
+                "theme desconocido {:?}; se usa tokyo-night",
+                config.theme
+            ));
+            normalized.theme = "tokyo-night".to_string();
+        }
+    }
+
+    if let Some(raw) = &config.default_date_range {
+        let range = raw.trim();
+        if range.is_empty() {
+            problems.push("default_date_range vacio; se quita".to_string());
+            normalized.default_date_range = None;
+        } else if let Err(error) = parse_date_range(range) {
+            problems.push(format!(
+                "default_date_range invalido ({range}): {error}; se quita"
+            ));
+            normalized.default_date_range = None;
+        } else if range != raw {
+            problems.push(format!("default_date_range tiene espacios: {raw:?}"));
+            normalized.default_date_range = Some(range.to_string());
+        }
+    }
+
+    (normalized, problems)
+}
+
+fn print_raw_time_entries(client: &ApiClient, range: &DateRange) -> Result<(), String> {
+    let body = client.fetch_raw(
+        "time-entries",
+        &[("start_date", &range.start), ("end_date", &range.end)],
+    )?;
+    println!("{body}");
+    Ok(())
+}
+
+fn build_client_and_config() -> Result<(AppConfig, ApiClient), String> {
+    let config = load_config();
+    let token = resolve_token(&config);
+    if token.is_empty() {
+        return Err(
+            "No hay token configurado. Define VAR_TOKEN, guarda var_token en la configuracion o agrega token= en ~/.vartui/credentials."
+                .to_string(),
+        );
     }
+    let base_url = resolve_base_url(&config);
 
+    // create_method is only validated by the commands that create entries
     let client = ApiClient::new(base_url, token)?;
     Ok((config, client))
 }
 
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
 fn resolve_range(input: Option<String>, config: &AppConfig) -> Result<DateRange, String> {
     let raw = input
         .or_else(|| config.default_date_range.clone())
@@ -276,27 +832,89 @@ fn resolve_range(input: Option<String>, config: &AppConfig) -> Result<DateRange,
     parse_date_range(&raw).map_err(|error| format!("Rango invalido ({raw}): {error}"))
 }
 
-fn parse_pretty_flag(args: &[String]) -> Result<bool, String> {
-    let mut pretty = false;
+fn parse_pretty_flag(args: &[String], help_text: &str) -> Result<bool, String> {
+    let mut pretty = default_pretty();
     for arg in args {
         match arg.as_str() {
             "--pretty" => pretty = true,
+            "--compact" => pretty = false,
             unknown => {
-                return Err(format!("Flag desconocida: {unknown}\n\n{PROJECTS_HELP}"));
+                return Err(format!("Flag desconocida: {unknown}\n\n{help_text}"));
             }
         }
     }
     Ok(pretty)
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ListFormat {
+    Json,
+    Ics,
+    Text,
+}
+
+#[derive(Clone, Copy)]
+enum SortField {
+    Hours,
+    Date,
+    Project,
+}
+
+#[derive(Clone, Copy)]
+struct EntrySort {
+    field: SortField,
+    descending: bool,
+}
+
+// "hours", "date:desc", "project:asc"
+fn parse_entry_sort(value: &str) -> Result<EntrySort, String> {
+    let (field, direction) = value.split_once(':').unwrap_or((value, "asc"));
+    let field = match field.trim().to_ascii_lowercase().as_str() {
+        "hours" => SortField::Hours,
+        "date" => SortField::Date,
+        "project" => SortField::Project,
+        _ => {
+            return Err(format!(
+                "Valor invalido para --sort: {value} (usa hours|date|project con :asc o :desc)"
+            ));
+        }
+    };
+    let descending = match direction.trim().to_ascii_lowercase().as_str() {
+        "asc" => false,
+        "desc" => true,
+        _ => {
+            return Err(format!(
+                "Valor invalido para --sort: {value} (usa :asc o :desc)"
+            ));
+        }
+    };
+    Ok(EntrySort { field, descending })
+}
+
 struct ListOptions {
     range: Option<String>,
     pretty: bool,
+    raw: bool,
+    billable: Option<bool>,
+    include_empty: bool,
+    group_by_client: bool,
+    project_id: Option<i32>,
+    sort: Option<EntrySort>,
+    format: ListFormat,
+    output: Option<String>,
 }
 
 fn parse_list_options(args: &[String], help_text: &str) -> Result<ListOptions, String> {
     let mut range: Option<String> = None;
-    let mut pretty = false;
+    let mut pretty = default_pretty();
+    let mut raw = false;
+    let mut billable: Option<bool> = None;
+    let mut include_empty = true;
+    let mut group_by_client = false;
+    let mut project_id: Option<i32> = None;
+    let mut sort: Option<EntrySort> = None;
+    let mut format = ListFormat::Json;
+    let mut output: Option<String> = None;
 
     let mut i = 0usize;
     while i < args.len() {
@@ -306,7 +924,66 @@ fn parse_list_options(args: &[String], help_text: &str) -> Result<ListOptions, S
                 let value = args.get(i).ok_or("Falta valor para --range")?;
                 range = Some(value.clone());
             }
+            "--billable" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --billable")?;
+                billable = Some(
+                    parse_bool(value)
+                        .ok_or_else(|| format!("Valor invalido para --billable: {value}"))?,
+                );
+            }
+            "--include-empty" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --include-empty")?;
+                include_empty = parse_bool(value)
+                    .ok_or_else(|| format!("Valor invalido para --include-empty: {value}"))?;
+            }
+            "--group-by" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --group-by")?;
+                if value != "client" {
+                    return Err(format!(
+                        "Valor invalido para --group-by: {value} (usa client)"
+                    ));
+                }
+                group_by_client = true;
+            }
+            "--project-id" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --project-id")?;
+                project_id = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Valor invalido para --project-id: {value}"))?,
+                );
+            }
+            "--sort" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --sort")?;
+                sort = Some(parse_entry_sort(value)?);
+            }
+            "--format" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --format")?;
+                format = match value.as_str() {
+                    "json" => ListFormat::Json,
+                    "ics" => ListFormat::Ics,
+                    "text" => ListFormat::Text,
+                    _ => {
+                        return Err(format!(
+                            "Valor invalido para --format: {value} (usa json|ics|text)"
+                        ));
+                    }
+                };
+            }
+            "--output" => {
+                i += 1;
+                let value = args.get(i).ok_or("Falta valor para --output")?;
+                output = Some(value.clone());
+            }
             "--pretty" => pretty = true,
+            "--compact" => pretty = false,
+            "--raw" => raw = true,
             unknown => {
                 return Err(format!("Flag desconocida: {unknown}\n\n{help_text}"));
             }
@@ -314,7 +991,26 @@ fn parse_list_options(args: &[String], help_text: &str) -> Result<ListOptions, S
         i += 1;
     }
 
-    Ok(ListOptions { range, pretty })
+    Ok(ListOptions {
+        range,
+        pretty,
+        raw,
+        billable,
+        include_empty,
+        group_by_client,
+        project_id,
+        sort,
+        format,
+        output,
+    })
+}
+
+// VARTUI_PRETTY=true flips the default; --pretty/--compact still win
+fn default_pretty() -> bool {
+    env::var("VARTUI_PRETTY")
+        .ok()
+        .and_then(|value| parse_bool(&value))
+        .unwrap_or(false)
 }
 
 fn print_json<T: Serialize>(value: &T, pretty: bool) -> Result<(), String> {
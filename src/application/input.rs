@@ -1,6 +1,7 @@
 use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::application::app::{App, AppFocus, InputMode};
+use crate::application::app::{App, AppFocus, FormField, InputMode};
+use crate::utils::i18n::{Text, tr};
 
 pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool {
     if code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL) {
@@ -18,8 +19,140 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
         return false;
     }
 
+    if app.input_mode == InputMode::NamingBookmark {
+        match code {
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Enter => app.submit_bookmark_name(),
+            KeyCode::Backspace => app.input_backspace(),
+            KeyCode::Char(value) => app.input_push(value),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::EditingDayNote {
+        match code {
+            KeyCode::Esc => app.cancel_input(),
+            KeyCode::Enter => app.submit_day_note(),
+            KeyCode::Backspace => app.input_backspace(),
+            KeyCode::Char(value) => app.day_note_input(value),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::ConfirmingClearDay {
+        match code {
+            KeyCode::Esc => {
+                app.cancel_input();
+                app.status = tr(app.config.language, Text::StatusClearDayCancelled).to_string();
+            }
+            KeyCode::Enter => app.confirm_clear_day(),
+            KeyCode::Backspace => app.input_backspace(),
+            KeyCode::Char(value) => app.input_push(value),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::ConfirmingUndo {
+        match code {
+            KeyCode::Enter => app.confirm_undo(),
+            KeyCode::Esc => {
+                app.cancel_input();
+                app.status = tr(app.config.language, Text::StatusUndoCancelled).to_string();
+            }
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::AddingMinutes {
+        match code {
+            KeyCode::Esc => {
+                app.cancel_input();
+                app.status = tr(app.config.language, Text::StatusCancelled).to_string();
+            }
+            KeyCode::Enter => app.confirm_add_minutes(),
+            KeyCode::Backspace => app.input_backspace(),
+            KeyCode::Char(value) => app.input_push(value),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::PickingBookmark {
+        match code {
+            KeyCode::Esc => app.close_bookmark_picker(),
+            KeyCode::Enter => app.apply_selected_bookmark(),
+            KeyCode::Down | KeyCode::Char('j') => app.bookmark_next(),
+            KeyCode::Up | KeyCode::Char('k') => app.bookmark_previous(),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::PickingTemplate {
+        match code {
+            KeyCode::Esc => app.close_template_picker(),
+            KeyCode::Enter => app.apply_selected_template(),
+            KeyCode::Down | KeyCode::Char('j') => app.template_next(),
+            KeyCode::Up | KeyCode::Char('k') => app.template_previous(),
+            _ => {}
+        }
+        return false;
+    }
+
+    if app.input_mode == InputMode::Searching {
+        match code {
+            KeyCode::Esc => app.close_search(),
+            KeyCode::Enter => app.apply_search_result(),
+            KeyCode::Down => app.search_next(),
+            KeyCode::Up => app.search_previous(),
+            KeyCode::Backspace => app.search_backspace(),
+            KeyCode::Char(value) => app.search_push(value),
+            _ => {}
+        }
+        return false;
+    }
+
     if app.input_mode == InputMode::AddingEntry {
+        if app.show_entry_preview {
+            app.toggle_entry_preview();
+            return false;
+        }
         match code {
+            KeyCode::Char('v') if modifiers.contains(KeyModifiers::CONTROL) => {
+                app.toggle_entry_preview()
+            }
+            KeyCode::Char('s') if modifiers.contains(KeyModifiers::CONTROL) => app.submit_entry(),
+            KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => app.retry_submit(),
+            KeyCode::Char('e') if modifiers.contains(KeyModifiers::CONTROL) => {
+                app.form_focus_first_incomplete()
+            }
+            KeyCode::Char('d')
+                if modifiers.contains(KeyModifiers::CONTROL)
+                    && app
+                        .entry_form
+                        .as_ref()
+                        .is_some_and(|form| form.focused == FormField::ProjectId) =>
+            {
+                app.form_clear_project()
+            }
+            // Ctrl+Enter needs keyboard enhancement support; Alt+Enter works everywhere
+            KeyCode::Enter if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                app.submit_and_continue()
+            }
+            // Alt keeps plain digits typing into the minutes field
+            KeyCode::Char(value @ '1'..='9')
+                if modifiers.contains(KeyModifiers::ALT)
+                    && app
+                        .entry_form
+                        .as_ref()
+                        .is_some_and(|form| form.focused == FormField::Minutes) =>
+            {
+                app.apply_minute_preset(value as usize - '0' as usize)
+            }
             KeyCode::Esc => app.close_add_entry(),
             KeyCode::BackTab => app.form_prev_field(),
             KeyCode::Tab => app.form_next_field(),
@@ -38,8 +171,8 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
             KeyCode::Esc => app.close_config(),
             KeyCode::BackTab => app.config_prev_field(),
             KeyCode::Tab => app.config_next_field(),
-            KeyCode::Up => app.config_theme_previous(),
-            KeyCode::Down => app.config_theme_next(),
+            KeyCode::Up => app.config_nav_up(),
+            KeyCode::Down => app.config_nav_down(),
             KeyCode::Enter => app.save_config_form(),
             KeyCode::Backspace => app.config_backspace(),
             KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
@@ -48,6 +181,9 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
             KeyCode::Char('r') if modifiers.contains(KeyModifiers::CONTROL) => {
                 app.config_reset_defaults()
             }
+            KeyCode::Char('d') if modifiers.contains(KeyModifiers::CONTROL) => {
+                app.config_use_current_range()
+            }
             KeyCode::Char(value) => app.config_input(value),
             _ => {}
         }
@@ -55,10 +191,17 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
     }
 
     match code {
+        _ if app.focus == AppFocus::Entries
+            && is_focus_days_key(&app.config.focus_days_keys, code, modifiers) =>
+        {
+            app.focus_days()
+        }
         KeyCode::Char('q') => return true,
         KeyCode::Down | KeyCode::Char('j') => {
             if app.focus == AppFocus::Entries {
                 app.next_entry();
+            } else if app.week_steps {
+                app.page_days(true);
             } else {
                 app.next_day();
             }
@@ -66,15 +209,45 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
         KeyCode::Up | KeyCode::Char('k') => {
             if app.focus == AppFocus::Entries {
                 app.previous_entry();
+            } else if app.week_steps {
+                app.page_days(false);
             } else {
                 app.previous_day();
             }
         }
+        KeyCode::PageDown if app.focus == AppFocus::Days => app.page_days(true),
+        KeyCode::PageUp if app.focus == AppFocus::Days => app.page_days(false),
+        KeyCode::Char('w') if app.focus == AppFocus::Days => app.toggle_week_steps(),
         KeyCode::Char('l') => app.focus_entries(),
-        KeyCode::Char('h') | KeyCode::Esc => app.focus_days(),
         KeyCode::Char('d') => app.open_duplicate_entry(),
+        KeyCode::Char('e') => app.open_edit_entry(),
+        KeyCode::Char('+') => app.start_add_minutes(),
+        KeyCode::Char('y') if app.focus == AppFocus::Days => app.copy_day_as_json(),
+        KeyCode::Char('y') => app.copy_entry_as_command(),
+        KeyCode::Char('Y') => app.copy_range_label(),
+        KeyCode::Char('s') if app.focus == AppFocus::Days => app.copy_summary(false),
+        KeyCode::Char('S') => app.copy_summary(true),
+        KeyCode::Char('L') => app.toggle_http_trace(),
         KeyCode::Char('r') => app.refresh(),
+        KeyCode::Char('P') => app.reload_projects(),
+        KeyCode::Char('z') if modifiers.contains(KeyModifiers::CONTROL) => app.start_undo(),
+        KeyCode::Char('z') => app.toggle_hide_empty_days(),
+        KeyCode::Char('<') => app.narrow_days_panel(),
+        KeyCode::Char('>') => app.widen_days_panel(),
+        KeyCode::Char('O') if app.focus == AppFocus::Days => app.toggle_sort_under_target(),
+        KeyCode::Char('D') if app.focus == AppFocus::Days => app.start_clear_day(),
+        KeyCode::Char('N') if app.focus == AppFocus::Days => app.start_day_note(),
+        KeyCode::Char('E') => app.export_range_ics(),
+        KeyCode::Char('x') => app.cycle_billable_filter(),
+        KeyCode::Char('p') => app.toggle_project_filter(),
+        KeyCode::Char('m') => app.toggle_show_minutes(),
+        KeyCode::Left if modifiers.contains(KeyModifiers::CONTROL) => app.cycle_theme(false),
+        KeyCode::Right if modifiers.contains(KeyModifiers::CONTROL) => app.cycle_theme(true),
+        KeyCode::Char('/') => app.open_search(),
         KeyCode::Char('f') => app.start_input(),
+        KeyCode::Char('b') => app.start_bookmark_name(),
+        KeyCode::Char('B') => app.open_bookmark_picker(),
+        KeyCode::Char('T') => app.open_template_picker(),
         KeyCode::Char('n') => app.open_add_entry(),
         KeyCode::Char('c') => app.open_config(),
         _ => {}
@@ -82,3 +255,23 @@ pub fn handle_key(app: &mut App, code: KeyCode, modifiers: KeyModifiers) -> bool
 
     false
 }
+
+// `focus_days_keys` entries: one character (case-sensitive) or a named key
+fn is_focus_days_key(keys: &[String], code: KeyCode, modifiers: KeyModifiers) -> bool {
+    if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+        return false;
+    }
+    keys.iter().any(|key| {
+        let key = key.trim();
+        let mut chars = key.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return code == KeyCode::Char(ch);
+        }
+        match key.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => code == KeyCode::Esc,
+            "left" => code == KeyCode::Left,
+            "backspace" => code == KeyCode::Backspace,
+            _ => false,
+        }
+    })
+}
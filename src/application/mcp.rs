@@ -1,26 +1,42 @@
 use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, BufWriter, Write};
 
+use chrono::Datelike;
 use crossterm::event::{KeyCode, KeyModifiers};
 use serde::Deserialize;
 use serde_json::{Map, Value, json};
 use toon::{Delimiter, EncodeOptions};
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use crate::application::app::{App, AppFocus, ConfigField, FormField, InputMode};
+use crate::application::app::{
+    App, AppFocus, ConfigField, FormField, InputMode, LoadState, entry_request_issues,
+};
 use crate::application::input::handle_key;
-use crate::utils::parsing::parse_date_range;
+use crate::domain::targets::{is_under_target, target_hours};
+use crate::infrastructure::api_client::{rate_limit_retry_after, rate_limited_error};
+use crate::infrastructure::config::load_config;
+use crate::infrastructure::credentials::resolve_token;
+use crate::infrastructure::http_tape::{self, TapeEntry};
+use crate::log;
+use crate::utils::i18n::{Text, tr};
+use crate::utils::parsing::{format_hhmm, parse_date, parse_date_range};
 use crate::utils::version::build_version;
 
-const MCP_HELP: &str = "Uso:\n  mcp\n\nInicia un servidor MCP por stdio para automatizar el TUI con respuestas compactas en TOON.";
-
 type ArgsMap = Map<String, Value>;
 
+const REDACTED: &str = "<redacted>";
+// Shorter values would mangle unrelated text in the transcript; real tokens are much longer
+const MIN_SECRET_LEN: usize = 8;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum SnapshotView {
     None,
     Tiny,
+    // Tiny plus per-day totals and targets, without entries
+    Week,
     Normal,
     Full,
 }
@@ -30,8 +46,9 @@ impl SnapshotView {
         match self {
             SnapshotView::None => 0,
             SnapshotView::Tiny => 1,
-            SnapshotView::Normal => 2,
-            SnapshotView::Full => 3,
+            SnapshotView::Week => 2,
+            SnapshotView::Normal => 3,
+            SnapshotView::Full => 4,
         }
     }
 
@@ -40,28 +57,75 @@ impl SnapshotView {
     }
 }
 
+#[derive(Default)]
+struct ActionOutcome {
+    exit: bool,
+    data: Option<Value>,
+}
+
+impl ActionOutcome {
+    fn exit(exit: bool) -> Self {
+        Self { exit, data: None }
+    }
+
+    fn data(data: Value) -> Self {
+        Self {
+            exit: false,
+            data: Some(data),
+        }
+    }
+}
+
+// Encoding of the text content; TOON is the token-efficient default
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TextFormat {
+    Toon,
+    Json,
+}
+
 struct ResponseOptions {
     include_structured: bool,
+    format: TextFormat,
     view: SnapshotView,
     max_days: usize,
     max_entries: usize,
+    // None follows the session config (`duration_display`)
+    hhmm: Option<bool>,
 }
 
 pub fn mcp_help() -> &'static str {
-    "  mcp"
+    "  mcp [--replay <ARCHIVO>]"
+}
+
+fn mcp_usage() -> String {
+    let lang = load_config().language;
+    format!(
+        "{}\n  mcp [--replay <ARCHIVO>]\n\n{}\n\n{}",
+        tr(lang, Text::HelpUsage),
+        tr(lang, Text::McpHelp),
+        tr(lang, Text::McpRecordHelp)
+    )
 }
 
 pub fn run_mcp(args: &[String]) -> Result<(), String> {
     if args.iter().any(|value| is_help(value.as_str())) {
-        println!("{MCP_HELP}");
+        println!("{}", mcp_usage());
         return Ok(());
     }
 
-    if !args.is_empty() {
-        return Err(format!(
-            "Flag desconocida para mcp: {}\n\n{MCP_HELP}",
-            args[0]
-        ));
+    match args {
+        [] => {}
+        [flag, path] if flag == "--replay" => return replay_transcript(path),
+        [flag] if flag == "--replay" => {
+            return Err(format!("--replay requiere un archivo\n\n{}", mcp_usage()));
+        }
+        _ => {
+            return Err(format!(
+                "Flag desconocida para mcp: {}\n\n{}",
+                args[0],
+                mcp_usage()
+            ));
+        }
     }
 
     let stdin = io::stdin();
@@ -69,6 +133,7 @@ pub fn run_mcp(args: &[String]) -> Result<(), String> {
     let mut reader = BufReader::new(stdin.lock());
     let mut writer = BufWriter::new(stdout.lock());
     let mut state = ServerState::default();
+    let mut recorder = open_recorder();
 
     loop {
         let payload = match read_framed_message(&mut reader) {
@@ -81,24 +146,10 @@ pub fn run_mcp(args: &[String]) -> Result<(), String> {
             continue;
         }
 
-        let request: RpcRequest = match serde_json::from_str(&payload) {
-            Ok(value) => value,
-            Err(error) => {
-                let response = json!({
-                    "jsonrpc": "2.0",
-                    "id": null,
-                    "error": {
-                        "code": -32700,
-                        "message": format!("JSON invalido: {error}")
-                    }
-                });
-                write_framed_message(&mut writer, &response)
-                    .map_err(|err| format!("Error enviando respuesta MCP: {err}"))?;
-                continue;
-            }
-        };
-
-        let outcome = handle_rpc_request(request, &mut state);
+        let outcome = handle_payload(&payload, &mut state);
+        if let Some(file) = recorder.as_mut() {
+            record_exchange(file, &payload, outcome.response.as_ref(), &state);
+        }
         if let Some(response) = outcome.response {
             write_framed_message(&mut writer, &response)
                 .map_err(|error| format!("Error enviando respuesta MCP: {error}"))?;
@@ -112,6 +163,176 @@ pub fn run_mcp(args: &[String]) -> Result<(), String> {
     Ok(())
 }
 
+fn handle_payload(payload: &str, state: &mut ServerState) -> RpcOutcome {
+    match serde_json::from_str::<RpcRequest>(payload) {
+        Ok(request) => handle_rpc_request(request, state),
+        Err(error) => RpcOutcome {
+            response: Some(json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": {
+                    "code": -32700,
+                    "message": format!("JSON invalido: {error}")
+                }
+            })),
+            exit: false,
+        },
+    }
+}
+
+// VARTUI_MCP_RECORD=<path> appends one {"req", "res", "http"} line per message; `mcp --replay` reads it back
+fn open_recorder() -> Option<File> {
+    let path = env::var("VARTUI_MCP_RECORD").ok()?;
+    let path = path.trim();
+    if path.is_empty() {
+        return None;
+    }
+    match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => {
+            http_tape::start_recording();
+            Some(file)
+        }
+        Err(error) => {
+            log!("Could not open MCP record file {}: {}", path, error);
+            None
+        }
+    }
+}
+
+fn record_exchange(file: &mut File, payload: &str, response: Option<&Value>, state: &ServerState) {
+    let line = transcript_line(payload, response, state);
+    if let Err(error) = writeln!(file, "{line}") {
+        log!("Could not write MCP record: {}", error);
+    }
+}
+
+// Requests that are not valid JSON are kept as the raw string so replay hits the same error.
+// `http` holds the API exchanges made meanwhile so replay never needs the network.
+fn transcript_line(payload: &str, response: Option<&Value>, state: &ServerState) -> Value {
+    let mut request = serde_json::from_str::<Value>(payload)
+        .unwrap_or_else(|_| Value::String(payload.to_string()));
+    if let Some(args) = request.pointer_mut("/params/arguments") {
+        redact_config_token(args);
+    }
+    let mut line = json!({ "req": request, "res": response });
+    let http = http_tape::drain_recorded();
+    if !http.is_empty() {
+        line["http"] = json!(http);
+    }
+    redact_secrets(&mut line, &state.secrets());
+    line
+}
+
+// set_config_field for the token, alone or inside an `actions` batch
+fn redact_config_token(args: &mut Value) {
+    if let Some(actions) = args.get_mut("actions").and_then(Value::as_array_mut) {
+        actions.iter_mut().for_each(redact_config_token);
+    }
+    let Some(map) = args.as_object_mut() else {
+        return;
+    };
+    let text = |keys: &[&str]| keys.iter().find_map(|key| map.get(*key)?.as_str());
+    let sets_token = text(&["action", "a"]).map(normalize_action) == Some("set_config_field")
+        && matches!(text(&["field", "f"]), Some("token" | "t"));
+    if !sets_token {
+        return;
+    }
+    for key in ["value", "v"] {
+        if let Some(value) = map.get_mut(key) {
+            *value = json!(REDACTED);
+        }
+    }
+}
+
+fn redact_secrets(value: &mut Value, secrets: &[String]) {
+    match value {
+        Value::String(text) => {
+            for secret in secrets {
+                if text.contains(secret.as_str()) {
+                    *text = text.replace(secret.as_str(), REDACTED);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| redact_secrets(item, secrets)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| redact_secrets(item, secrets)),
+        _ => {}
+    }
+}
+
+// Feeds every recorded request to a fresh server and prints the responses that differ.
+// API calls are answered from the recorded `http` exchanges, never the network.
+fn replay_transcript(path: &str) -> Result<(), String> {
+    let contents =
+        fs::read_to_string(path).map_err(|error| format!("No se pudo leer {path}: {error}"))?;
+    let (total, divergences) = replay_lines(path, &contents)?;
+
+    println!("replay: {total} solicitudes, {divergences} divergencias");
+    if divergences > 0 {
+        return Err(format!("{divergences} respuestas distintas a la grabacion"));
+    }
+    Ok(())
+}
+
+fn replay_lines(path: &str, contents: &str) -> Result<(usize, usize), String> {
+    let mut records = Vec::new();
+    let mut http = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Value = serde_json::from_str(line)
+            .map_err(|error| format!("{path}:{}: linea invalida: {error}", index + 1))?;
+        if let Some(exchanges) = record.get("http") {
+            let exchanges: Vec<TapeEntry> = serde_json::from_value(exchanges.clone())
+                .map_err(|error| format!("{path}:{}: `http` invalido: {error}", index + 1))?;
+            http.extend(exchanges);
+        }
+        records.push((index + 1, record));
+    }
+
+    // Background loads can land a line later than the request that started them
+    http_tape::start_replay(http);
+    let result = replay_records(path, &records);
+    http_tape::stop();
+    result
+}
+
+fn replay_records(path: &str, records: &[(usize, Value)]) -> Result<(usize, usize), String> {
+    let mut state = ServerState::default();
+    let mut total = 0;
+    let mut divergences = 0;
+
+    for (line_number, record) in records {
+        let payload = match record.get("req") {
+            Some(Value::String(raw)) => raw.clone(),
+            Some(request) => request.to_string(),
+            None => return Err(format!("{path}:{line_number}: falta `req`")),
+        };
+        let expected = record.get("res").cloned().unwrap_or(Value::Null);
+
+        total += 1;
+        let actual = handle_payload(&payload, &mut state)
+            .response
+            .unwrap_or(Value::Null);
+        if actual != expected {
+            divergences += 1;
+            let method = record
+                .pointer("/req/method")
+                .and_then(Value::as_str)
+                .unwrap_or("?");
+            println!("{path}:{line_number} {method}");
+            println!("  grabado:  {expected}");
+            println!("  obtenido: {actual}");
+        }
+    }
+
+    Ok((total, divergences))
+}
+
 #[derive(Default)]
 struct ServerState {
     next_session_id: u64,
@@ -136,6 +357,23 @@ impl ServerState {
     fn close_session(&mut self, session_id: &str) -> bool {
         self.sessions.remove(session_id).is_some()
     }
+
+    // Every token a session could print: the resolved one and whatever the config form holds
+    fn secrets(&self) -> Vec<String> {
+        let mut secrets: Vec<String> = self
+            .sessions
+            .values()
+            .flat_map(|app| {
+                let form_token = app.config_form.as_ref().map(|form| form.token.clone());
+                [Some(resolve_token(&app.config)), form_token]
+            })
+            .flatten()
+            .filter(|secret| secret.len() >= MIN_SECRET_LEN)
+            .collect();
+        secrets.sort();
+        secrets.dedup();
+        secrets
+    }
 }
 
 struct RpcOutcome {
@@ -171,7 +409,7 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                             "name": "vartui-mcp",
                             "version": build_version()
                         },
-                        "instructions": "Servidor MCP headless para el TUI. Usa `vartui.session.action` para menos tokens y `view=tiny|none` para respuestas minimas."
+                        "instructions": tr(load_config().language, Text::McpInstructions)
                     }),
                 )
             });
@@ -197,14 +435,18 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                         "inputSchema": {
                             "type": "object",
                             "properties": {
-                                "view": {"type": "string", "enum": ["none", "tiny", "normal", "full"]},
-                                "vw": {"type": "string", "enum": ["n", "t", "f", "0"]},
+                                "view": {"type": "string", "enum": ["none", "tiny", "week", "normal", "full"]},
+                                "vw": {"type": "string", "enum": ["n", "t", "w", "f", "0"]},
                                 "max_days": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "md": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "max_entries_per_day": {"type": "integer", "minimum": 1, "maximum": 300},
                                 "me": {"type": "integer", "minimum": 1, "maximum": 300},
+                                "duration": {"type": "string", "enum": ["hours", "hhmm"]},
+                                "du": {"type": "string", "enum": ["h", "hm"]},
                                 "structured": {"type": "boolean"},
-                                "stc": {"type": "boolean"}
+                                "stc": {"type": "boolean"},
+                                "format": {"type": "string", "enum": ["toon", "json"]},
+                                "fm": {"type": "string", "enum": ["t", "j"]}
                             }
                         }
                     },
@@ -217,14 +459,18 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                             "properties": {
                                 "session_id": {"type": "string"},
                                 "sid": {"type": "string"},
-                                "view": {"type": "string", "enum": ["none", "tiny", "normal", "full"]},
-                                "vw": {"type": "string", "enum": ["n", "t", "f", "0"]},
+                                "view": {"type": "string", "enum": ["none", "tiny", "week", "normal", "full"]},
+                                "vw": {"type": "string", "enum": ["n", "t", "w", "f", "0"]},
                                 "max_days": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "md": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "max_entries_per_day": {"type": "integer", "minimum": 1, "maximum": 300},
                                 "me": {"type": "integer", "minimum": 1, "maximum": 300},
+                                "duration": {"type": "string", "enum": ["hours", "hhmm"]},
+                                "du": {"type": "string", "enum": ["h", "hm"]},
                                 "structured": {"type": "boolean"},
-                                "stc": {"type": "boolean"}
+                                "stc": {"type": "boolean"},
+                                "format": {"type": "string", "enum": ["toon", "json"]},
+                                "fm": {"type": "string", "enum": ["t", "j"]}
                             }
                         }
                     },
@@ -241,14 +487,18 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                                 "k": {"type": "string"},
                                 "text": {"type": "string"},
                                 "t": {"type": "string"},
-                                "view": {"type": "string", "enum": ["none", "tiny", "normal", "full"]},
-                                "vw": {"type": "string", "enum": ["n", "t", "f", "0"]},
+                                "view": {"type": "string", "enum": ["none", "tiny", "week", "normal", "full"]},
+                                "vw": {"type": "string", "enum": ["n", "t", "w", "f", "0"]},
                                 "max_days": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "md": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "max_entries_per_day": {"type": "integer", "minimum": 1, "maximum": 300},
                                 "me": {"type": "integer", "minimum": 1, "maximum": 300},
+                                "duration": {"type": "string", "enum": ["hours", "hhmm"]},
+                                "du": {"type": "string", "enum": ["h", "hm"]},
                                 "structured": {"type": "boolean"},
-                                "stc": {"type": "boolean"}
+                                "stc": {"type": "boolean"},
+                                "format": {"type": "string", "enum": ["toon", "json"]},
+                                "fm": {"type": "string", "enum": ["t", "j"]}
                             }
                         }
                     },
@@ -275,16 +525,21 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                                 "k": {"type": "string"},
                                 "text": {"type": "string"},
                                 "t": {"type": "string"},
+                                "name": {"type": "string"},
                                 "index": {"type": "integer", "minimum": 0},
                                 "i": {"type": "integer", "minimum": 0},
-                                "view": {"type": "string", "enum": ["none", "tiny", "normal", "full"]},
-                                "vw": {"type": "string", "enum": ["n", "t", "f", "0"]},
+                                "view": {"type": "string", "enum": ["none", "tiny", "week", "normal", "full"]},
+                                "vw": {"type": "string", "enum": ["n", "t", "w", "f", "0"]},
                                 "max_days": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "md": {"type": "integer", "minimum": 1, "maximum": 120},
                                 "max_entries_per_day": {"type": "integer", "minimum": 1, "maximum": 300},
                                 "me": {"type": "integer", "minimum": 1, "maximum": 300},
+                                "duration": {"type": "string", "enum": ["hours", "hhmm"]},
+                                "du": {"type": "string", "enum": ["h", "hm"]},
                                 "structured": {"type": "boolean"},
-                                "stc": {"type": "boolean"}
+                                "stc": {"type": "boolean"},
+                                "format": {"type": "string", "enum": ["toon", "json"]},
+                                "fm": {"type": "string", "enum": ["t", "j"]}
                             }
                         }
                     },
@@ -298,7 +553,9 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
                                 "session_id": {"type": "string"},
                                 "sid": {"type": "string"},
                                 "structured": {"type": "boolean"},
-                                "stc": {"type": "boolean"}
+                                "stc": {"type": "boolean"},
+                                "format": {"type": "string", "enum": ["toon", "json"]},
+                                "fm": {"type": "string", "enum": ["t", "j"]}
                             }
                         }
                     }
@@ -314,7 +571,9 @@ fn handle_rpc_request(request: RpcRequest, state: &mut ServerState) -> RpcOutcom
             let response = id.map(|rpc_id| {
                 let result = match handle_tool_call(&request.params, state) {
                     Ok(ok) => ok,
-                    Err(message) => tool_error_result(&message),
+                    Err(message) => {
+                        tool_error_result(&message, requested_text_format(&request.params))
+                    }
                 };
                 rpc_result(rpc_id, result)
             });
@@ -379,7 +638,11 @@ fn tool_session_create(args: &ArgsMap, state: &mut ServerState) -> Result<Value,
         "sid": session_id,
         "s": snapshot
     });
-    Ok(build_tool_result(content, options.include_structured))
+    Ok(build_tool_result(
+        content,
+        options.include_structured,
+        options.format,
+    ))
 }
 
 fn tool_session_snapshot(args: &ArgsMap, state: &mut ServerState) -> Result<Value, String> {
@@ -394,7 +657,11 @@ fn tool_session_snapshot(args: &ArgsMap, state: &mut ServerState) -> Result<Valu
         "sid": session_id,
         "s": snapshot
     });
-    Ok(build_tool_result(content, options.include_structured))
+    Ok(build_tool_result(
+        content,
+        options.include_structured,
+        options.format,
+    ))
 }
 
 fn tool_session_key(args: &ArgsMap, state: &mut ServerState) -> Result<Value, String> {
@@ -407,6 +674,7 @@ fn tool_session_key(args: &ArgsMap, state: &mut ServerState) -> Result<Value, St
     let mut exit_requested = false;
     {
         let app = state.get_session_mut(&session_id)?;
+        let cooldown_before = app.rate_limited_until;
         for (code, modifiers) in &sequence {
             if handle_key(app, *code, *modifiers) {
                 exit_requested = true;
@@ -414,6 +682,7 @@ fn tool_session_key(args: &ArgsMap, state: &mut ServerState) -> Result<Value, St
             }
             app.check_background_load();
         }
+        check_new_rate_limit(app, cooldown_before)?;
     }
 
     let snapshot = if exit_requested {
@@ -437,7 +706,11 @@ fn tool_session_key(args: &ArgsMap, state: &mut ServerState) -> Result<Value, St
         "x": exit_requested,
         "s": snapshot
     });
-    Ok(build_tool_result(content, options.include_structured))
+    Ok(build_tool_result(
+        content,
+        options.include_structured,
+        options.format,
+    ))
 }
 
 fn tool_session_action(args: &ArgsMap, state: &mut ServerState) -> Result<Value, String> {
@@ -449,22 +722,28 @@ fn tool_session_action(args: &ArgsMap, state: &mut ServerState) -> Result<Value,
     let mut exit_requested = false;
     let mut last_action = String::new();
     let mut actions_applied: Vec<String> = Vec::new();
+    let mut results: Vec<Value> = Vec::new();
 
     {
         let app = state.get_session_mut(&session_id)?;
+        let cooldown_before = app.rate_limited_until;
         for (action, step_args) in &steps {
             let normalized = normalize_action(action);
-            let step_exit = apply_action(app, normalized, step_args)?;
+            let outcome = apply_action(app, normalized, step_args)?;
             applied += 1;
             last_action = normalized.to_string();
             if options.view.at_least(SnapshotView::Normal) {
                 actions_applied.push(normalized.to_string());
             }
-            if step_exit {
+            if let Some(data) = outcome.data {
+                results.push(data);
+            }
+            if outcome.exit {
                 exit_requested = true;
                 break;
             }
         }
+        check_new_rate_limit(app, cooldown_before)?;
     }
 
     let snapshot = if exit_requested {
@@ -495,11 +774,27 @@ fn tool_session_action(args: &ArgsMap, state: &mut ServerState) -> Result<Value,
         map.insert("as".to_string(), json!(actions_applied));
     }
 
-    Ok(build_tool_result(content, options.include_structured))
+    if !results.is_empty()
+        && let Some(map) = content.as_object_mut()
+    {
+        let value = if results.len() == 1 {
+            results.remove(0)
+        } else {
+            Value::Array(results)
+        };
+        map.insert("r".to_string(), value);
+    }
+
+    Ok(build_tool_result(
+        content,
+        options.include_structured,
+        options.format,
+    ))
 }
 
 fn tool_session_close(args: &ArgsMap, state: &mut ServerState) -> Result<Value, String> {
     let include_structured = parse_bool_alias(args, &["structured", "stc"], false)?;
+    let format = parse_text_format(arg(args, &["format", "fm"]))?;
     let session_id = parse_session_id(args)?;
     let removed = state.close_session(&session_id);
     let content = json!({
@@ -507,7 +802,7 @@ fn tool_session_close(args: &ArgsMap, state: &mut ServerState) -> Result<Value,
         "sid": session_id,
         "c": removed
     });
-    Ok(build_tool_result(content, include_structured))
+    Ok(build_tool_result(content, include_structured, format))
 }
 
 fn parse_action_steps(args: &ArgsMap) -> Result<Vec<(String, ArgsMap)>, String> {
@@ -535,22 +830,140 @@ fn parse_action_steps(args: &ArgsMap) -> Result<Vec<(String, ArgsMap)>, String>
     Ok(vec![(action, args.clone())])
 }
 
-fn apply_action(app: &mut App, action: &str, args: &ArgsMap) -> Result<bool, String> {
+fn apply_action(app: &mut App, action: &str, args: &ArgsMap) -> Result<ActionOutcome, String> {
     match action {
         "noop" => {}
         "refresh" => app.refresh(),
+        "reload_projects" => app.reload_projects(),
         "focus_days" => app.focus_days(),
         "focus_entries" => app.focus_entries(),
         "next_day" => app.next_day(),
         "previous_day" => app.previous_day(),
+        "toggle_hide_empty_days" => app.toggle_hide_empty_days(),
+        "toggle_sort_under_target" => app.toggle_sort_under_target(),
+        "toggle_week_steps" => app.toggle_week_steps(),
+        "page_days" => {
+            let forward = parse_bool_alias(args, &["forward", "fw"], true)?;
+            app.page_days(forward);
+        }
+        "preview_entry" => {
+            let request = app
+                .entry_request()
+                .ok_or_else(|| "No hay formulario de registro abierto".to_string())?;
+            let mut payload = serde_json::to_value(&request).map_err(|e| e.to_string())?;
+            let issues = entry_request_issues(app.config.language, &request);
+            if !issues.is_empty()
+                && let Some(map) = payload.as_object_mut()
+            {
+                map.insert("_issues".to_string(), json!(issues));
+            }
+            return Ok(ActionOutcome::data(payload));
+        }
+        "clear_day" => {
+            if !parse_bool_alias(args, &["force"], false)? {
+                return Err(
+                    "clear_day borra todos los registros del dia; requiere force:true".to_string(),
+                );
+            }
+            let report = app.delete_day_entries()?;
+            return Ok(ActionOutcome::data(json!({
+                "d": report.date,
+                "n": report.total,
+                "ok": report.deleted,
+                "er": report.errors
+            })));
+        }
+        "load_entry" => {
+            let id = load_entry_arg(app, args)?;
+            return Ok(ActionOutcome::data(json!({ "id": id })));
+        }
+        "edit_entry" => {
+            let id = load_entry_arg(app, args)?;
+            // `date` picks the entry, `new_date` moves it
+            let fields = [
+                ("new_date", "date"),
+                ("project_id", "project_id"),
+                ("description", "description"),
+                ("minutes", "minutes"),
+                ("billable", "billable"),
+            ];
+            for (key, field) in fields {
+                if let Some(value) = args.get(key) {
+                    let mut field_args = ArgsMap::new();
+                    field_args.insert("f".to_string(), json!(field));
+                    field_args.insert("v".to_string(), value.clone());
+                    set_entry_field(app, &field_args)?;
+                }
+            }
+            submit_entry_form(app, args, "submit_entry")?;
+            if app.entry_form.is_some() {
+                return Err(app.status.clone());
+            }
+            return Ok(ActionOutcome::data(json!({ "id": id })));
+        }
+        "add_minutes_to_entry" => {
+            let delta = parse_required_i32_alias(args, &["minutes", "m", "delta"])?;
+            if delta <= 0 {
+                return Err("minutes debe ser mayor a 0".to_string());
+            }
+            let (id, minutes) = app.add_minutes_to_entry(delta)?;
+            return Ok(ActionOutcome::data(json!({
+                "id": id,
+                "mode": app.config.add_minutes_mode,
+                "m": minutes
+            })));
+        }
+        "cycle_billable_filter" => app.cycle_billable_filter(),
+        "set_project_filter" => set_project_filter(app, args)?,
         "next_entry" => app.next_entry(),
         "previous_entry" => app.previous_entry(),
         "open_duplicate_entry" => app.open_duplicate_entry(),
         "open_add_entry" => app.open_add_entry(),
         "close_add_entry" => app.close_add_entry(),
-        "submit_entry" => app.submit_entry(),
+        "submit_entry" | "submit_and_continue" => {
+            let editing_id = app.entry_form.as_ref().and_then(|form| form.editing_id);
+            submit_entry_form(app, args, action)?;
+            if let Some(id) = editing_id
+                && app
+                    .entry_form
+                    .as_ref()
+                    .is_none_or(|form| form.editing_id.is_none())
+            {
+                return Ok(ActionOutcome::data(json!({ "id": id })));
+            }
+        }
+        "retry_submit" => {
+            if !app
+                .entry_form
+                .as_ref()
+                .is_some_and(|form| form.submit_failed)
+            {
+                return Err("No hay envio fallido que reintentar".to_string());
+            }
+            // Same warn_below_minutes / force contract as submit_entry
+            submit_entry_form(app, args, "submit_entry")?;
+            if app.entry_form.is_some() {
+                return Err(app.status.clone());
+            }
+        }
+        "clear_project" => app.form_clear_project(),
         "entry_next_field" => app.form_next_field(),
         "entry_prev_field" => app.form_prev_field(),
+        "focus_first_incomplete" => {
+            let form = app
+                .entry_form
+                .as_mut()
+                .ok_or_else(|| "No hay formulario de entrada abierto".to_string())?;
+            let moved = form.focus_first_incomplete();
+            return Ok(ActionOutcome::data(json!({
+                "f": form_field_code(form.focused),
+                "c": !moved
+            })));
+        }
+        "targets" => {
+            let only_under = parse_bool_alias(args, &["only_under", "u"], false)?;
+            return Ok(ActionOutcome::data(targets_data(app, only_under)));
+        }
         "entry_enter" => app.form_enter(),
         "entry_nav_up" => app.form_nav_up(),
         "entry_nav_down" => app.form_nav_down(),
@@ -564,6 +977,12 @@ fn apply_action(app: &mut App, action: &str, args: &ArgsMap) -> Result<bool, Str
         "config_next_field" => app.config_next_field(),
         "config_backspace" => app.config_backspace(),
         "config_reset_defaults" => app.config_reset_defaults(),
+        "config_use_current_range" => {
+            if app.config_form.is_none() {
+                app.open_config();
+            }
+            app.config_use_current_range();
+        }
         "config_clear_field" => clear_config_field(app, args)?,
         "set_config_field" => set_config_field(app, args)?,
         "open_range_editor" => app.start_input(),
@@ -580,11 +999,49 @@ fn apply_action(app: &mut App, action: &str, args: &ArgsMap) -> Result<bool, Str
         "send_key" => {
             let key = parse_required_string_alias(args, &["key", "k"])?;
             let text = arg(args, &["text", "t"]).and_then(Value::as_str);
-            return execute_key_sequence(app, &key, text);
+            return execute_key_sequence(app, &key, text).map(ActionOutcome::exit);
         }
         "type_text" => {
             let text = parse_required_string_alias(args, &["text", "t"])?;
-            return execute_key_sequence(app, "text", Some(text.as_str()));
+            return execute_key_sequence(app, "text", Some(text.as_str())).map(ActionOutcome::exit);
+        }
+        "get_day_note" => {
+            let date = day_note_date(app, args)?;
+            let note = app.day_note(&date).map(str::to_string);
+            return Ok(ActionOutcome::data(json!({ "d": date, "n": note })));
+        }
+        "set_day_note" => {
+            let date = day_note_date(app, args)?;
+            let note = match arg(args, &["note", "n", "value", "v"]) {
+                Some(value) => parse_string_value(value, "note")?,
+                None => String::new(),
+            };
+            app.set_day_note(&date, &note)?;
+            let note = app.day_note(&date).map(str::to_string);
+            return Ok(ActionOutcome::data(json!({ "d": date, "n": note })));
+        }
+        "list_bookmarks" => {
+            let bookmarks = app
+                .bookmark_names()
+                .into_iter()
+                .map(|name| {
+                    let range = app.config.range_bookmarks.get(&name).cloned();
+                    json!({ "n": name, "r": range })
+                })
+                .collect::<Vec<Value>>();
+            return Ok(ActionOutcome::data(Value::Array(bookmarks)));
+        }
+        "save_bookmark" => {
+            let name = parse_required_string_alias(args, &["name", "value", "v"])?;
+            app.save_bookmark(&name)?;
+        }
+        "apply_bookmark" => {
+            let name = parse_required_string_alias(args, &["name", "value", "v"])?;
+            app.apply_bookmark(&name)?;
+        }
+        "apply_template" => {
+            let name = parse_required_string_alias(args, &["name", "value", "v"])?;
+            app.apply_template(&name)?;
         }
         other => {
             return Err(format!("Accion no soportada: {other}"));
@@ -592,7 +1049,7 @@ fn apply_action(app: &mut App, action: &str, args: &ArgsMap) -> Result<bool, Str
     }
 
     app.check_background_load();
-    Ok(false)
+    Ok(ActionOutcome::default())
 }
 
 fn execute_key_sequence(app: &mut App, key: &str, text: Option<&str>) -> Result<bool, String> {
@@ -619,27 +1076,84 @@ fn normalize_action(action: &str) -> &str {
         "ne" => "next_entry",
         "pe" => "previous_entry",
         "fd" => "focus_days",
+        "hz" => "toggle_hide_empty_days",
+        "so" => "toggle_sort_under_target",
+        "ws" => "toggle_week_steps",
+        "pg" => "page_days",
+        "cd" => "clear_day",
+        "bf" => "cycle_billable_filter",
+        "pf" => "set_project_filter",
+        "pv" => "preview_entry",
         "fe" => "focus_entries",
         "rf" => "refresh",
+        "rp" => "reload_projects",
         "oa" => "open_add_entry",
         "ca" => "close_add_entry",
         "se" => "submit_entry",
+        "sc" => "submit_and_continue",
+        "rt" => "retry_submit",
         "sf" => "set_entry_field",
         "sp" => "select_project",
+        "cp" => "clear_project",
         "tb" => "toggle_billable",
         "oc" => "open_config",
         "cc" => "close_config",
         "sv" => "save_config",
         "scf" => "set_config_field",
         "clf" => "config_clear_field",
+        "ucr" => "config_use_current_range",
         "sr" => "set_range",
+        "lb" => "list_bookmarks",
+        "sb" => "save_bookmark",
+        "ab" => "apply_bookmark",
+        "at" => "apply_template",
         "sk" => "send_key",
         "tt" => "type_text",
         "dup" => "open_duplicate_entry",
+        "le" => "load_entry",
+        "ee" => "edit_entry",
+        "am" => "add_minutes_to_entry",
+        "ffi" => "focus_first_incomplete",
+        "tg" => "targets",
+        "gdn" => "get_day_note",
+        "sdn" => "set_day_note",
         _ => action,
     }
 }
 
+// `date` when given, otherwise the selected day
+fn day_note_date(app: &App, args: &ArgsMap) -> Result<String, String> {
+    match arg(args, &["date", "d"]) {
+        Some(value) => {
+            let value = parse_string_value(value, "date")?;
+            parse_date(&value)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .ok_or_else(|| format!("fecha invalida: {value}"))
+        }
+        None => app
+            .selected_day()
+            .map(|day| day.date.clone())
+            .ok_or_else(|| "No hay dia seleccionado".to_string()),
+    }
+}
+
+// `project_id` filters by id; without it the filter is cleared
+fn set_project_filter(app: &mut App, args: &ArgsMap) -> Result<(), String> {
+    let Some(value) = arg(args, &["project_id", "pid"]) else {
+        app.project_filter = None;
+        return Ok(());
+    };
+    let project_id = parse_i32_value(value, "project_id")?;
+    let name = app
+        .projects
+        .iter()
+        .find(|project| project.id == project_id)
+        .map(|project| project.name.clone())
+        .unwrap_or_else(|| format!("Proyecto {project_id}"));
+    app.set_project_filter(project_id, name);
+    Ok(())
+}
+
 fn toggle_billable(app: &mut App) -> Result<(), String> {
     if app.entry_form.is_none() {
         app.open_add_entry();
@@ -653,6 +1167,30 @@ fn toggle_billable(app: &mut App) -> Result<(), String> {
     Ok(())
 }
 
+fn submit_entry_form(app: &mut App, args: &ArgsMap, action: &str) -> Result<(), String> {
+    if let Some((minutes, threshold)) = app.short_entry_warning() {
+        if !parse_bool_alias(args, &["force"], false)? {
+            return Err(format!(
+                "{minutes} min es menor a warn_below_minutes ({threshold}); requiere force:true"
+            ));
+        }
+        app.confirm_short_entry();
+    }
+    if action == "submit_and_continue" {
+        app.submit_and_continue();
+    } else {
+        app.submit_entry();
+    }
+    Ok(())
+}
+
+fn load_entry_arg(app: &mut App, args: &ArgsMap) -> Result<i32, String> {
+    let date = parse_required_string_alias(args, &["date", "d"])?;
+    let index = parse_usize_alias(args, &["index", "i"])
+        .ok_or_else(|| "index requerido (posicion del registro en el dia)".to_string())?;
+    app.load_entry(&date, index)
+}
+
 fn set_entry_field(app: &mut App, args: &ArgsMap) -> Result<(), String> {
     if app.entry_form.is_none() {
         app.open_add_entry();
@@ -777,6 +1315,7 @@ fn select_project(app: &mut App, args: &ArgsMap) -> Result<(), String> {
         .entry_form
         .as_mut()
         .ok_or_else(|| "No hay formulario de entrada".to_string())?;
+    form.apply_billable_default(&app.config, project.id);
     form.selected_project = Some(project.clone());
     form.project_search = project.name;
     form.filtered_indices.clear();
@@ -801,6 +1340,11 @@ fn set_config_field(app: &mut App, args: &ArgsMap) -> Result<(), String> {
         return Ok(());
     }
 
+    if matches!(field.as_str(), "default_range" | "range" | "r") {
+        app.config_set_range_value(value);
+        return Ok(());
+    }
+
     let form = app
         .config_form
         .as_mut()
@@ -809,7 +1353,6 @@ fn set_config_field(app: &mut App, args: &ArgsMap) -> Result<(), String> {
     match field.as_str() {
         "token" | "t" => form.token = value,
         "base_url" | "url" | "u" => form.base_url = value,
-        "default_range" | "range" | "r" => form.default_range = value,
         "focused" | "focus" => {
             form.focused = parse_config_field(value.as_str())?;
         }
@@ -835,6 +1378,11 @@ fn clear_config_field(app: &mut App, args: &ArgsMap) -> Result<(), String> {
             return Ok(());
         }
 
+        if target == ConfigField::DefaultRange {
+            app.config_set_range_value(String::new());
+            return Ok(());
+        }
+
         let form = app
             .config_form
             .as_mut()
@@ -878,17 +1426,65 @@ fn parse_response_options(
     default_view: SnapshotView,
 ) -> Result<ResponseOptions, String> {
     let include_structured = parse_bool_alias(args, &["structured", "stc"], false)?;
+    let format = parse_text_format(arg(args, &["format", "fm"]))?;
     let view = parse_snapshot_view(arg(args, &["view", "vw"]), default_view)?;
     let max_days = parse_limit(arg(args, &["max_days", "md"]), 14, 120)?;
     let max_entries = parse_limit(arg(args, &["max_entries_per_day", "me"]), 20, 300)?;
+    let hhmm = parse_duration_display(arg(args, &["duration", "du"]))?;
     Ok(ResponseOptions {
         include_structured,
+        format,
         view,
         max_days,
         max_entries,
+        hhmm,
     })
 }
 
+fn parse_text_format(raw: Option<&Value>) -> Result<TextFormat, String> {
+    let Some(raw) = raw else {
+        return Ok(TextFormat::Toon);
+    };
+
+    let value = raw
+        .as_str()
+        .ok_or_else(|| "format debe ser string".to_string())?
+        .trim()
+        .to_ascii_lowercase();
+
+    match value.as_str() {
+        "toon" | "t" => Ok(TextFormat::Toon),
+        "json" | "j" => Ok(TextFormat::Json),
+        _ => Err(format!("format invalido: {value} (usa toon|json)")),
+    }
+}
+
+fn parse_duration_display(raw: Option<&Value>) -> Result<Option<bool>, String> {
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+
+    let value = raw
+        .as_str()
+        .ok_or_else(|| "duration debe ser string".to_string())?
+        .trim()
+        .to_ascii_lowercase();
+
+    match value.as_str() {
+        "hours" | "h" => Ok(Some(false)),
+        "hhmm" | "hm" => Ok(Some(true)),
+        _ => Err(format!("duration invalido: {value}")),
+    }
+}
+
+fn hours_value(hours: f32, hhmm: bool) -> Value {
+    if hhmm {
+        Value::String(format_hhmm(hours))
+    } else {
+        json!(hours)
+    }
+}
+
 fn parse_snapshot_view(raw: Option<&Value>, default: SnapshotView) -> Result<SnapshotView, String> {
     let Some(raw) = raw else {
         return Ok(default);
@@ -903,6 +1499,7 @@ fn parse_snapshot_view(raw: Option<&Value>, default: SnapshotView) -> Result<Sna
     match value.as_str() {
         "none" | "0" => Ok(SnapshotView::None),
         "tiny" | "t" => Ok(SnapshotView::Tiny),
+        "week" | "w" => Ok(SnapshotView::Week),
         "normal" | "n" => Ok(SnapshotView::Normal),
         "full" | "f" => Ok(SnapshotView::Full),
         _ => Err(format!("view invalido: {value}")),
@@ -910,15 +1507,18 @@ fn parse_snapshot_view(raw: Option<&Value>, default: SnapshotView) -> Result<Sna
 }
 
 fn build_snapshot(session_id: &str, app: &App, options: &ResponseOptions) -> Option<Value> {
+    let hhmm = options.hhmm.unwrap_or_else(|| app.config.uses_hhmm());
     match options.view {
         SnapshotView::None => None,
         SnapshotView::Tiny => Some(build_tiny_snapshot(session_id, app)),
-        SnapshotView::Normal => Some(build_normal_snapshot(session_id, app)),
+        SnapshotView::Week => Some(build_week_snapshot(session_id, app, hhmm)),
+        SnapshotView::Normal => Some(build_normal_snapshot(session_id, app, hhmm)),
         SnapshotView::Full => Some(build_full_snapshot(
             session_id,
             app,
             options.max_days,
             options.max_entries,
+            hhmm,
         )),
     }
 }
@@ -933,11 +1533,70 @@ fn build_tiny_snapshot(session_id: &str, app: &App) -> Value {
         "ei": app.entry_state.selected(),
         "dc": app.days.len(),
         "pc": app.projects.len(),
+        "dl": load_state_code(&app.days_load),
+        "pl": load_state_code(&app.projects_load),
+        "rl": app.rate_limit_remaining_secs(),
         "st": clip_text(&app.status, 120)
     })
 }
 
-fn build_normal_snapshot(session_id: &str, app: &App) -> Value {
+// Per-day target and what is missing, in minutes, so agents can plan fills in one call.
+// `only_under` keeps past/today days below target (future days have not been missed yet).
+fn targets_data(app: &App, only_under: bool) -> Value {
+    let weekend = app.config.weekend_mode;
+    let today = chrono::Local::now().date_naive();
+    let mut remaining_total = 0;
+    let days = app
+        .days
+        .iter()
+        .filter(|day| !only_under || is_under_target(day, today, weekend))
+        .filter_map(|day| {
+            let date = parse_date(&day.date)?;
+            let target = (target_hours(date.weekday(), weekend) * 60.0).round() as i32;
+            let logged = day.total_minutes();
+            let remaining = (target - logged).max(0);
+            remaining_total += remaining;
+            Some(json!({
+                "d": day.date,
+                "wd": date.format("%a").to_string(),
+                "tg": target,
+                "m": logged,
+                "r": remaining
+            }))
+        })
+        .collect::<Vec<Value>>();
+    json!({ "ds": days, "tr": remaining_total })
+}
+
+// "Is my week filled": one row per day in the range, no entries
+fn build_week_snapshot(session_id: &str, app: &App, hhmm: bool) -> Value {
+    let mut snapshot = build_tiny_snapshot(session_id, app);
+    let weekend = app.config.weekend_mode;
+    let days = app
+        .days
+        .iter()
+        .map(|day| {
+            let total = day.total_hours();
+            let target = parse_date(&day.date)
+                .map(|date| target_hours(date.weekday(), weekend))
+                .unwrap_or(0.0);
+            json!({
+                "d": day.date,
+                "th": hours_value(total, hhmm),
+                "tg": hours_value(target, hhmm),
+                "m": total >= target
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    if let Some(map) = snapshot.as_object_mut() {
+        map.insert("wk".to_string(), Value::Array(days));
+    }
+
+    snapshot
+}
+
+fn build_normal_snapshot(session_id: &str, app: &App, hhmm: bool) -> Value {
     let mut snapshot = build_tiny_snapshot(session_id, app);
 
     if let Some(map) = snapshot.as_object_mut() {
@@ -948,7 +1607,7 @@ fn build_normal_snapshot(session_id: &str, app: &App) -> Value {
                     json!({
                         "d": day.date,
                         "ec": day.entries.len(),
-                        "th": day.total_hours()
+                        "th": hours_value(day.total_hours(), hhmm)
                     })
                 })
                 .unwrap_or(Value::Null),
@@ -960,7 +1619,7 @@ fn build_normal_snapshot(session_id: &str, app: &App) -> Value {
                 .map(|entry| {
                     json!({
                         "p": clip_text(&entry.project, 48),
-                        "h": entry.hours,
+                        "h": hours_value(entry.hours, hhmm),
                         "n": clip_text(&entry.note, 140)
                     })
                 })
@@ -1005,8 +1664,14 @@ fn build_normal_snapshot(session_id: &str, app: &App) -> Value {
     snapshot
 }
 
-fn build_full_snapshot(session_id: &str, app: &App, max_days: usize, max_entries: usize) -> Value {
-    let mut snapshot = build_normal_snapshot(session_id, app);
+fn build_full_snapshot(
+    session_id: &str,
+    app: &App,
+    max_days: usize,
+    max_entries: usize,
+    hhmm: bool,
+) -> Value {
+    let mut snapshot = build_normal_snapshot(session_id, app, hhmm);
 
     let days = app
         .days
@@ -1020,7 +1685,7 @@ fn build_full_snapshot(session_id: &str, app: &App, max_days: usize, max_entries
                 .map(|entry| {
                     json!({
                         "p": clip_text(&entry.project, 64),
-                        "h": entry.hours,
+                        "h": hours_value(entry.hours, hhmm),
                         "n": clip_text(&entry.note, 220)
                     })
                 })
@@ -1028,7 +1693,7 @@ fn build_full_snapshot(session_id: &str, app: &App, max_days: usize, max_entries
 
             json!({
                 "d": day.date,
-                "th": day.total_hours(),
+                "th": hours_value(day.total_hours(), hhmm),
                 "ec": day.entries.len(),
                 "e": entries
             })
@@ -1042,8 +1707,8 @@ fn build_full_snapshot(session_id: &str, app: &App, max_days: usize, max_entries
     snapshot
 }
 
-fn build_tool_result(content: Value, include_structured: bool) -> Value {
-    let text = encode_toon_compact(&content);
+fn build_tool_result(content: Value, include_structured: bool, format: TextFormat) -> Value {
+    let text = encode_text(&content, format);
     if include_structured {
         json!({
             "content": [{
@@ -1062,25 +1727,61 @@ fn build_tool_result(content: Value, include_structured: bool) -> Value {
     }
 }
 
-fn tool_error_result(message: &str) -> Value {
-    let payload = json!({
-        "e": "er",
-        "m": clip_text(message, 220)
-    });
+// A 429 hit during the call surfaces as an error so clients can back off
+fn check_new_rate_limit(app: &App, cooldown_before: Option<Instant>) -> Result<(), String> {
+    if app.rate_limited_until == cooldown_before {
+        return Ok(());
+    }
+    match app.rate_limit_remaining_secs() {
+        Some(secs) => Err(rate_limited_error(Duration::from_secs(secs))),
+        None => Ok(()),
+    }
+}
+
+fn tool_error_result(message: &str, format: TextFormat) -> Value {
+    let payload = match rate_limit_retry_after(message) {
+        Some(retry_after) => json!({
+            "e": "rl",
+            "m": clip_text(message, 220),
+            "ra": retry_after.as_secs()
+        }),
+        None => json!({
+            "e": "er",
+            "m": clip_text(message, 220)
+        }),
+    };
 
     json!({
         "content": [{
             "type": "text",
-            "text": encode_toon_compact(&payload)
+            "text": encode_text(&payload, format)
         }],
         "isError": true
     })
 }
 
+fn encode_text(value: &Value, format: TextFormat) -> String {
+    match format {
+        TextFormat::Toon => encode_toon_compact(value),
+        TextFormat::Json => serde_json::to_string(value).unwrap_or_default(),
+    }
+}
+
+// Errors follow the requested format when it parses, TOON otherwise
+fn requested_text_format(params: &Value) -> TextFormat {
+    params
+        .get("arguments")
+        .and_then(Value::as_object)
+        .and_then(|args| parse_text_format(arg(args, &["format", "fm"])).ok())
+        .unwrap_or(TextFormat::Toon)
+}
+
 fn encode_toon_compact(value: &Value) -> String {
-    let mut options = EncodeOptions::default();
-    options.indent = 1;
-    options.delimiter = Delimiter::Tab;
+    let options = EncodeOptions {
+        indent: 1,
+        delimiter: Delimiter::Tab,
+        ..Default::default()
+    };
     toon::encode(value, Some(options))
 }
 
@@ -1213,34 +1914,74 @@ fn parse_key_sequence(
         return Ok(vec![(KeyCode::Char(ch), KeyModifiers::NONE)]);
     }
 
-    let mapped = match key {
-        "up" => Some((KeyCode::Up, KeyModifiers::NONE)),
-        "down" => Some((KeyCode::Down, KeyModifiers::NONE)),
-        "left" => Some((KeyCode::Char('h'), KeyModifiers::NONE)),
-        "right" => Some((KeyCode::Char('l'), KeyModifiers::NONE)),
-        "enter" => Some((KeyCode::Enter, KeyModifiers::NONE)),
-        "esc" => Some((KeyCode::Esc, KeyModifiers::NONE)),
-        "tab" => Some((KeyCode::Tab, KeyModifiers::NONE)),
-        "backtab" => Some((KeyCode::BackTab, KeyModifiers::NONE)),
-        "backspace" => Some((KeyCode::Backspace, KeyModifiers::NONE)),
-        "space" => Some((KeyCode::Char(' '), KeyModifiers::NONE)),
-        "ctrl+c" => Some((KeyCode::Char('c'), KeyModifiers::CONTROL)),
-        "ctrl+r" => Some((KeyCode::Char('r'), KeyModifiers::CONTROL)),
-        "ctrl+u" => Some((KeyCode::Char('u'), KeyModifiers::CONTROL)),
-        "j" | "k" | "h" | "l" | "q" | "r" | "f" | "n" | "d" | "c" => {
-            let ch = key.chars().next().ok_or_else(|| "key vacia".to_string())?;
-            Some((KeyCode::Char(ch), KeyModifiers::NONE))
-        }
-        _ => None,
+    parse_key_chord(key).map(|key_event| vec![key_event])
+}
+
+// Parses `mod1+mod2+key` chords (ctrl/alt/shift + char or named key like f5, home)
+fn parse_key_chord(raw: &str) -> Result<(KeyCode, KeyModifiers), String> {
+    let unsupported = || {
+        format!(
+            "Key no soportada: {raw}. Usa text, char:<x>, teclas del TUI o combinaciones como ctrl+shift+x."
+        )
     };
 
-    if let Some(key_event) = mapped {
-        Ok(vec![key_event])
+    let (mods_part, key_part) = if let Some(prefix) = raw.strip_suffix("++") {
+        (prefix, "+")
+    } else if raw.chars().count() == 1 {
+        ("", raw)
     } else {
-        Err(format!(
-            "Key no soportada: {key}. Usa text, char:<x>, o teclas del TUI."
-        ))
+        raw.rsplit_once('+').unwrap_or(("", raw))
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for name in mods_part.split('+').filter(|name| !name.is_empty()) {
+        modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => KeyModifiers::CONTROL,
+            "alt" | "meta" | "option" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return Err(unsupported()),
+        };
+    }
+
+    let mut chars = key_part.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        let ch = if modifiers.contains(KeyModifiers::SHIFT) {
+            ch.to_ascii_uppercase()
+        } else {
+            ch
+        };
+        return Ok((KeyCode::Char(ch), modifiers));
     }
+
+    let name = key_part.trim().to_ascii_lowercase();
+    let code = match name.as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        // Bare left/right keep their historical vim aliases (h/l)
+        "left" if modifiers.is_empty() => KeyCode::Char('h'),
+        "right" if modifiers.is_empty() => KeyCode::Char('l'),
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" | "pgup" => KeyCode::PageUp,
+        "pagedown" | "pgdn" => KeyCode::PageDown,
+        "delete" | "del" => KeyCode::Delete,
+        "insert" | "ins" => KeyCode::Insert,
+        other => match other.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+            Some(number) if (1..=12).contains(&number) => KeyCode::F(number),
+            _ => return Err(unsupported()),
+        },
+    };
+
+    Ok((code, modifiers))
 }
 
 fn input_mode_code(value: InputMode) -> &'static str {
@@ -1249,6 +1990,23 @@ fn input_mode_code(value: InputMode) -> &'static str {
         InputMode::Editing => "e",
         InputMode::AddingEntry => "a",
         InputMode::Configuring => "c",
+        InputMode::NamingBookmark => "bn",
+        InputMode::PickingBookmark => "bp",
+        InputMode::Searching => "s",
+        InputMode::ConfirmingClearDay => "cd",
+        InputMode::ConfirmingUndo => "cu",
+        InputMode::AddingMinutes => "am",
+        InputMode::PickingTemplate => "tp",
+        InputMode::EditingDayNote => "dn",
+    }
+}
+
+fn load_state_code(value: &LoadState) -> &'static str {
+    match value {
+        LoadState::Idle => "i",
+        LoadState::Loading => "l",
+        LoadState::Loaded => "ok",
+        LoadState::Failed(_) => "f",
     }
 }
 
@@ -1400,6 +2158,104 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn parse_modifier_chords() {
+        let sequence = parse_key_sequence("ctrl+shift+x", None).expect("expected valid chord");
+        assert_eq!(
+            sequence,
+            vec![(
+                KeyCode::Char('X'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )]
+        );
+
+        let sequence = parse_key_sequence("f5", None).expect("expected valid chord");
+        assert_eq!(sequence, vec![(KeyCode::F(5), KeyModifiers::NONE)]);
+
+        let sequence = parse_key_sequence("alt+home", None).expect("expected valid chord");
+        assert_eq!(sequence, vec![(KeyCode::Home, KeyModifiers::ALT)]);
+    }
+
+    #[test]
+    fn parse_legacy_key_aliases() {
+        let sequence = parse_key_sequence("left", None).expect("expected valid alias");
+        assert_eq!(sequence, vec![(KeyCode::Char('h'), KeyModifiers::NONE)]);
+
+        let sequence = parse_key_sequence("ctrl+u", None).expect("expected valid alias");
+        assert_eq!(sequence, vec![(KeyCode::Char('u'), KeyModifiers::CONTROL)]);
+
+        assert!(parse_key_sequence("hyper+x", None).is_err());
+    }
+
+    #[test]
+    fn rate_limited_errors_get_their_own_code() {
+        let message = format!("error: {}", rate_limited_error(Duration::from_secs(12)));
+        let text = tool_error_result(&message, TextFormat::Toon)["content"][0]["text"]
+            .as_str()
+            .expect("expected text content")
+            .to_string();
+        assert!(text.contains("e: rl"));
+        assert!(text.contains("ra: 12"));
+
+        let text =
+            tool_error_result("500 Internal Server Error", TextFormat::Toon)["content"][0]["text"]
+                .as_str()
+                .expect("expected text content")
+                .to_string();
+        assert!(text.contains("e: er"));
+    }
+
+    #[test]
+    fn json_format_keeps_text_parseable() {
+        let content = json!({"e": "ss", "s": {"dc": 3}});
+        let result = build_tool_result(content.clone(), false, TextFormat::Json);
+        let text = result["content"][0]["text"]
+            .as_str()
+            .expect("expected text content");
+        assert_eq!(
+            serde_json::from_str::<Value>(text).expect("text should be json"),
+            content
+        );
+        assert_eq!(
+            parse_text_format(Some(&Value::String("j".to_string()))).expect("format should parse"),
+            TextFormat::Json
+        );
+        assert!(parse_text_format(Some(&Value::String("xml".to_string()))).is_err());
+    }
+
+    #[test]
+    fn invalid_payload_gets_parse_error() {
+        let mut state = ServerState::default();
+        let outcome = handle_payload("{not json", &mut state);
+        assert!(!outcome.exit);
+        assert_eq!(outcome.response.expect("response")["error"]["code"], -32700);
+    }
+
+    #[test]
+    fn view_short_aliases_in_schema_parse() {
+        let mut state = ServerState::default();
+        let request = RpcRequest {
+            id: Some(json!(1)),
+            method: "tools/list".to_string(),
+            params: Value::Null,
+        };
+        let response = handle_rpc_request(request, &mut state)
+            .response
+            .expect("response");
+        let mut checked = 0;
+        for tool in response["result"]["tools"].as_array().expect("tools") {
+            let Some(values) = tool["inputSchema"]["properties"]["vw"]["enum"].as_array() else {
+                continue;
+            };
+            assert!(values.contains(&json!("w")));
+            for value in values {
+                assert!(parse_snapshot_view(Some(value), SnapshotView::Normal).is_ok());
+            }
+            checked += 1;
+        }
+        assert_eq!(checked, 4);
+    }
+
     #[test]
     fn parse_view_aliases() {
         assert!(matches!(
@@ -1412,11 +2268,87 @@ mod tests {
                 .expect("view should parse"),
             SnapshotView::None
         ));
+        assert!(matches!(
+            parse_snapshot_view(Some(&Value::String("w".to_string())), SnapshotView::Normal)
+                .expect("view should parse"),
+            SnapshotView::Week
+        ));
+        assert!(!SnapshotView::Week.at_least(SnapshotView::Normal));
+    }
+
+    #[test]
+    fn hours_value_respects_duration_display() {
+        assert_eq!(hours_value(7.5, true), Value::String("7:30".to_string()));
+        assert_eq!(hours_value(7.5, false), json!(7.5));
+        assert_eq!(
+            parse_duration_display(Some(&Value::String("hm".to_string())))
+                .expect("duration should parse"),
+            Some(true)
+        );
     }
 
     #[test]
     fn normalize_action_aliases() {
         assert_eq!(normalize_action("nd"), "next_day");
         assert_eq!(normalize_action("sf"), "set_entry_field");
+        assert_eq!(normalize_action("tg"), "targets");
+    }
+
+    #[test]
+    fn transcript_redacts_config_tokens() {
+        let state = ServerState::default();
+        let payload = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": "vartui.session.action",
+                "arguments": {
+                    "sid": "session-1",
+                    "actions": [
+                        {"a": "scf", "f": "t", "v": "secret-token-123"},
+                        {"a": "scf", "f": "u", "v": "http://localhost"}
+                    ]
+                }
+            }
+        })
+        .to_string();
+        let line = transcript_line(&payload, None, &state);
+        let actions = line
+            .pointer("/req/params/arguments/actions")
+            .expect("actions recorded");
+        assert_eq!(actions[0]["v"], json!(REDACTED));
+        assert_eq!(actions[1]["v"], json!("http://localhost"));
+
+        let mut value = json!({"res": ["Bearer secret-token-123", 7]});
+        redact_secrets(&mut value, &["secret-token-123".to_string()]);
+        assert_eq!(value, json!({"res": ["Bearer <redacted>", 7]}));
+    }
+
+    #[test]
+    fn recorded_transcript_replays_without_divergences() {
+        let payloads = [
+            json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}).to_string(),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}).to_string(),
+            json!({
+                "jsonrpc": "2.0",
+                "id": 3,
+                "method": "tools/call",
+                "params": {"name": "vartui.session.action", "arguments": {"sid": "nope", "a": "n"}}
+            })
+            .to_string(),
+            "{not json".to_string(),
+        ];
+        let mut state = ServerState::default();
+        let transcript: Vec<String> = payloads
+            .iter()
+            .map(|payload| {
+                let outcome = handle_payload(payload, &mut state);
+                transcript_line(payload, outcome.response.as_ref(), &state).to_string()
+            })
+            .collect();
+
+        let replayed = replay_lines("test.jsonl", &transcript.join("\n"));
+        assert_eq!(replayed, Ok((payloads.len(), 0)));
     }
 }
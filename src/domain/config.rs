@@ -1,4 +1,47 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Es,
+    En,
+}
+
+// How Saturdays and Sundays are judged: `bonus` has no target but logged hours
+// count as extra, `ignore` mutes them and leaves them out of the week, `counts`
+// gives them a workday target
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekendMode {
+    Ignore,
+    #[default]
+    Bonus,
+    Counts,
+}
+
+// `+` on an entry: `update` adds the minutes to it, `new` logs a separate entry
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddMinutesMode {
+    #[default]
+    Update,
+    New,
+}
+
+// Prefill for recurring entries, picked with `T` or the apply_template MCP action
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EntryTemplate {
+    pub name: String,
+    pub project_id: i32,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub minutes: i32,
+    #[serde(default = "default_true")]
+    pub billable: bool,
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -11,6 +54,97 @@ pub struct AppConfig {
     pub default_date_range: Option<String>,
     #[serde(default = "default_theme")]
     pub theme: String,
+    // "dark"/"light" forces theme = "auto" without querying the OS
+    #[serde(default)]
+    pub system_theme: Option<String>,
+    #[serde(default)]
+    pub hide_empty_days: bool,
+    #[serde(default)]
+    pub range_bookmarks: HashMap<String, String>,
+    // "hours" -> 7.5h, "hhmm" -> 7:30
+    #[serde(default = "default_duration_display")]
+    pub duration_display: String,
+    #[serde(default = "default_true")]
+    pub show_duration_bars: bool,
+    // None/0 disables auto-refresh
+    #[serde(default)]
+    pub auto_refresh_secs: Option<u64>,
+    // false: Enter on the last form field wraps to the first; submit with Ctrl+S
+    #[serde(default = "default_true")]
+    pub enter_submits_on_last: bool,
+    // Max concurrent POSTs for bulk creates
+    #[serde(default = "default_bulk_concurrency")]
+    pub bulk_concurrency: usize,
+    // UI, status and help strings: "es" (default) or "en"
+    #[serde(default)]
+    pub language: Language,
+    // Endpoint used to create entries, for backends that differ from POST /time-entries
+    #[serde(default = "default_create_method")]
+    pub create_method: String,
+    #[serde(default = "default_create_path")]
+    pub create_path: String,
+    // Re-read config.toml every N seconds when it changed on disk (default: off)
+    #[serde(default)]
+    pub config_reload_secs: Option<u64>,
+    // Keep the token masked even while editing it (screen sharing)
+    #[serde(default)]
+    pub always_mask_token: bool,
+    // Keys that leave the entries panel: single characters, "esc", "left" or "backspace"
+    #[serde(default = "default_focus_days_keys")]
+    pub focus_days_keys: Vec<String>,
+    // Alt+1..9 in the minutes field fills these values
+    #[serde(default = "default_minute_presets")]
+    pub minute_presets: Vec<u32>,
+    // Draw "[cliente]"/"[nota]" instead of client names and notes (demos, screenshots)
+    #[serde(default)]
+    pub hide_client_names: bool,
+    #[serde(default)]
+    pub hide_notes: bool,
+    // Range totals of 100h or more drop the decimal ("312h" instead of "312.5h")
+    #[serde(default)]
+    pub compact_totals: bool,
+    // Up/Down in the minutes field add or subtract this many minutes
+    #[serde(default = "default_minutes_step")]
+    pub minutes_step: u32,
+    // Entries shorter than this many minutes need a second submit (likely typos)
+    #[serde(default)]
+    pub warn_below_minutes: Option<i32>,
+    // Land on today's row after the first load when it is in the range
+    #[serde(default = "default_true")]
+    pub select_today_on_start: bool,
+    // Width of the days panel in percent; `<`/`>` adjust it (20-80)
+    #[serde(default = "default_days_split_percent")]
+    pub days_split_percent: u16,
+    // Workdays above target * this factor are drawn as a warning (possible double entries)
+    #[serde(default = "default_overtime_factor")]
+    pub overtime_factor: f32,
+    // Prefix entries with "[id]" to match API/CLI output
+    #[serde(default)]
+    pub show_project_id: bool,
+    // Prefix day rows with the short weekday name ("Lun 2024-01-15")
+    #[serde(default)]
+    pub show_weekday: bool,
+    #[serde(default)]
+    pub add_minutes_mode: AddMinutesMode,
+    // Drop 0-minute placeholder rows from days, totals and counts
+    #[serde(default)]
+    pub hide_zero_minute_entries: bool,
+    // Fetch days and projects on the calling thread instead of a background one
+    #[serde(default)]
+    pub synchronous_load: bool,
+    #[serde(default)]
+    pub entry_templates: Vec<EntryTemplate>,
+    // Most recent first; shown above the rest of the project dropdown
+    #[serde(default)]
+    pub recent_project_ids: Vec<i32>,
+    // Internal projects: picking one in the entry form unchecks billable
+    #[serde(default)]
+    pub non_billable_project_ids: Vec<i32>,
+    #[serde(default)]
+    pub weekend_mode: WeekendMode,
+    // Longest description the entry form accepts, in chars
+    #[serde(default = "default_max_description_len")]
+    pub max_description_len: usize,
 }
 
 impl Default for AppConfig {
@@ -20,10 +154,105 @@ impl Default for AppConfig {
             base_url: "https://var.elaniin.com/api".to_string(),
             default_date_range: None,
             theme: default_theme(),
+            system_theme: None,
+            hide_empty_days: false,
+            range_bookmarks: HashMap::new(),
+            duration_display: default_duration_display(),
+            show_duration_bars: true,
+            auto_refresh_secs: None,
+            enter_submits_on_last: true,
+            bulk_concurrency: default_bulk_concurrency(),
+            language: Language::default(),
+            create_method: default_create_method(),
+            create_path: default_create_path(),
+            config_reload_secs: None,
+            always_mask_token: false,
+            focus_days_keys: default_focus_days_keys(),
+            minute_presets: default_minute_presets(),
+            hide_client_names: false,
+            hide_notes: false,
+            compact_totals: false,
+            minutes_step: default_minutes_step(),
+            warn_below_minutes: None,
+            select_today_on_start: true,
+            days_split_percent: default_days_split_percent(),
+            overtime_factor: default_overtime_factor(),
+            show_project_id: false,
+            show_weekday: false,
+            add_minutes_mode: AddMinutesMode::default(),
+            hide_zero_minute_entries: false,
+            synchronous_load: false,
+            entry_templates: Vec::new(),
+            recent_project_ids: Vec::new(),
+            non_billable_project_ids: Vec::new(),
+            weekend_mode: WeekendMode::default(),
+            max_description_len: default_max_description_len(),
         }
     }
 }
 
+impl AppConfig {
+    pub fn uses_hhmm(&self) -> bool {
+        self.duration_display.trim().eq_ignore_ascii_case("hhmm")
+    }
+
+    pub fn uses_minutes(&self) -> bool {
+        self.duration_display.trim().eq_ignore_ascii_case("minutes")
+    }
+
+    pub fn is_non_billable_project(&self, project_id: i32) -> bool {
+        self.non_billable_project_ids.contains(&project_id)
+    }
+
+    pub fn add_minutes_creates_entry(&self) -> bool {
+        self.add_minutes_mode == AddMinutesMode::New
+    }
+}
+
 fn default_theme() -> String {
     "tokyo-night".to_string()
 }
+
+fn default_duration_display() -> String {
+    "hours".to_string()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_bulk_concurrency() -> usize {
+    3
+}
+
+fn default_create_method() -> String {
+    "POST".to_string()
+}
+
+fn default_create_path() -> String {
+    "/time-entries".to_string()
+}
+
+fn default_days_split_percent() -> u16 {
+    30
+}
+
+fn default_overtime_factor() -> f32 {
+    1.3
+}
+
+fn default_minutes_step() -> u32 {
+    15
+}
+
+fn default_max_description_len() -> usize {
+    500
+}
+
+fn default_focus_days_keys() -> Vec<String> {
+    vec!["h".to_string(), "esc".to_string()]
+}
+
+fn default_minute_presets() -> Vec<u32> {
+    vec![15, 30, 45, 60, 90, 120]
+}
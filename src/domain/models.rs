@@ -1,13 +1,42 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Entry {
+    // 0 when the API did not send an id
+    #[serde(default)]
+    pub id: i32,
     pub project: String,
+    // 0 when the API sent only a project name
+    #[serde(default)]
+    pub project_id: i32,
+    // Exact minutes from the API; `hours` is derived from them
+    #[serde(default)]
+    pub minutes: i32,
     pub hours: f32,
     pub note: String,
+    pub billable: bool,
+    #[serde(default)]
+    pub client: String,
 }
 
-#[derive(Clone, Serialize, Deserialize, Debug)]
+// Billable fixture with ids set; `hours` follows `minutes`
+#[cfg(test)]
+impl Entry {
+    pub fn sample(project: &str, minutes: i32, note: &str) -> Self {
+        Self {
+            id: 1,
+            project: project.to_string(),
+            project_id: 1,
+            minutes,
+            hours: minutes as f32 / 60.0,
+            note: note.to_string(),
+            billable: true,
+            client: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Day {
     pub date: String,
     pub entries: Vec<Entry>,
@@ -17,6 +46,10 @@ impl Day {
     pub fn total_hours(&self) -> f32 {
         self.entries.iter().map(|e| e.hours).sum()
     }
+
+    pub fn total_minutes(&self) -> i32 {
+        self.entries.iter().map(|e| e.minutes).sum()
+    }
 }
 
 #[derive(Clone, Deserialize)]
@@ -31,7 +64,8 @@ pub struct Project {
 
 #[derive(Clone, Deserialize)]
 pub struct TimeEntry {
-    // pub id: i32, // Unused
+    #[serde(default)]
+    pub id: i32,
     pub date: String,
     pub description: String,
     #[serde(rename = "projectId")]
@@ -42,6 +76,9 @@ pub struct TimeEntry {
     #[serde(default)]
     pub project_name: String,
     pub minutes: i32,
+    #[serde(rename = "isBillable", alias = "is_billable")]
+    #[serde(default)]
+    pub is_billable: Option<bool>,
 }
 
 #[derive(Clone, Deserialize)]
@@ -52,16 +89,33 @@ pub struct ProjectRef {
     pub name: String,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct CreateEntryRequest {
     pub date: String,
     pub project_id: i32,
     pub description: String,
     pub minutes: i32,
+    #[serde(default = "default_billable")]
     pub is_billable: bool,
+    #[serde(default)]
     pub tag_ids: Vec<i32>,
 }
 
+// Entry as the API echoed it after a create; fields it left out keep the submitted values
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CreatedEntry {
+    pub id: i32,
+    pub date: String,
+    pub project_id: i32,
+    pub description: String,
+    pub minutes: i32,
+    pub is_billable: bool,
+}
+
+fn default_billable() -> bool {
+    true
+}
+
 #[derive(Clone)]
 pub struct DateRange {
     pub start: String,
@@ -0,0 +1,142 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::domain::config::WeekendMode;
+use crate::domain::models::Day;
+use crate::utils::parsing::parse_date;
+
+pub fn is_weekend(weekday: Weekday) -> bool {
+    matches!(weekday, Weekday::Sat | Weekday::Sun)
+}
+
+pub fn target_hours(weekday: Weekday, weekend: WeekendMode) -> f32 {
+    match weekday {
+        Weekday::Fri => 8.0,
+        Weekday::Sat | Weekday::Sun if weekend == WeekendMode::Counts => 8.0,
+        Weekday::Sat | Weekday::Sun => 0.0,
+        _ => 9.0, // Mon-Thu
+    }
+}
+
+// Past/today workdays below their target; future days have not been missed yet
+pub fn is_under_target(day: &Day, today: NaiveDate, weekend: WeekendMode) -> bool {
+    match parse_date(&day.date) {
+        Some(date) if date <= today => day.total_hours() < target_hours(date.weekday(), weekend),
+        _ => false,
+    }
+}
+
+// Hours still missing to reach the day's target; None on days without one
+pub fn day_remaining_hours(day: &Day, weekend: WeekendMode) -> Option<f32> {
+    let target = target_hours(parse_date(&day.date)?.weekday(), weekend);
+    if target == 0.0 {
+        return None;
+    }
+    Some((target - day.total_hours()).max(0.0))
+}
+
+// Target from Monday through `until` minus what `days` has logged in that span.
+// Negative means the week is already above target. None if `days` misses part of the week.
+pub fn week_remaining_hours(days: &[Day], until: NaiveDate, weekend: WeekendMode) -> Option<f32> {
+    let monday = until - chrono::Duration::days(until.weekday().num_days_from_monday() as i64);
+
+    let mut target = 0.0;
+    let mut logged = 0.0;
+    let mut date = monday;
+    while date <= until {
+        let day = days
+            .iter()
+            .find(|day| parse_date(&day.date) == Some(date))?;
+        if !(weekend == WeekendMode::Ignore && is_weekend(date.weekday())) {
+            target += target_hours(date.weekday(), weekend);
+            logged += day.total_hours();
+        }
+        date += chrono::Duration::days(1);
+    }
+
+    Some(target - logged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Entry;
+
+    fn day(date: &str, hours: f32) -> Day {
+        let entries = if hours > 0.0 {
+            vec![Entry::sample("P", (hours * 60.0) as i32, "")]
+        } else {
+            Vec::new()
+        };
+        Day {
+            date: date.to_string(),
+            entries,
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        parse_date(value).expect("valid date")
+    }
+
+    // 2026-03-02 is a Monday
+    fn week() -> Vec<Day> {
+        vec![
+            day("2026-03-02", 9.0),
+            day("2026-03-03", 6.0),
+            day("2026-03-04", 0.0),
+            day("2026-03-05", 0.0),
+            day("2026-03-06", 0.0),
+            day("2026-03-07", 2.0),
+            day("2026-03-08", 0.0),
+        ]
+    }
+
+    #[test]
+    fn targets_per_weekday() {
+        assert_eq!(target_hours(Weekday::Mon, WeekendMode::Ignore), 9.0);
+        assert_eq!(target_hours(Weekday::Fri, WeekendMode::Ignore), 8.0);
+        assert_eq!(target_hours(Weekday::Sat, WeekendMode::Ignore), 0.0);
+        assert_eq!(target_hours(Weekday::Sun, WeekendMode::Counts), 8.0);
+    }
+
+    #[test]
+    fn under_target_only_counts_past_days() {
+        let today = date("2026-03-04");
+        let days = week();
+        assert!(!is_under_target(&days[0], today, WeekendMode::Ignore));
+        assert!(is_under_target(&days[1], today, WeekendMode::Ignore));
+        assert!(is_under_target(&days[2], today, WeekendMode::Ignore));
+        assert!(!is_under_target(&days[3], today, WeekendMode::Ignore));
+        assert_eq!(
+            day_remaining_hours(&days[1], WeekendMode::Ignore),
+            Some(3.0)
+        );
+        assert_eq!(day_remaining_hours(&days[5], WeekendMode::Ignore), None);
+    }
+
+    #[test]
+    fn week_remaining_runs_through_the_given_date() {
+        let days = week();
+        assert_eq!(
+            week_remaining_hours(&days, date("2026-03-03"), WeekendMode::Ignore),
+            Some(3.0)
+        );
+        assert_eq!(
+            week_remaining_hours(&days, date("2026-03-06"), WeekendMode::Ignore),
+            Some(29.0)
+        );
+        // Weekend hours only count when the weekend has a target
+        assert_eq!(
+            week_remaining_hours(&days, date("2026-03-08"), WeekendMode::Ignore),
+            Some(29.0)
+        );
+        assert_eq!(
+            week_remaining_hours(&days, date("2026-03-08"), WeekendMode::Counts),
+            Some(43.0)
+        );
+        // The range does not cover the whole week up to that date
+        assert_eq!(
+            week_remaining_hours(&days[1..], date("2026-03-04"), WeekendMode::Ignore),
+            None
+        );
+    }
+}
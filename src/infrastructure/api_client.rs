@@ -1,18 +1,180 @@
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, Request, RequestBuilder, Response};
+use reqwest::header::{ETAG, HeaderValue, IF_NONE_MATCH};
+use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use std::collections::HashMap;
-use std::time::Duration;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Mutex, OnceLock, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crate::domain::models::*;
+use crate::infrastructure::http_tape::{self, TapeEntry};
 use crate::log;
+use crate::utils::logging::trace_enabled;
+use crate::utils::parsing::parse_date;
+
+// Errors for HTTP 429 start with this marker so callers can back off
+pub const RATE_LIMITED_PREFIX: &str = "429 limite alcanzado";
+const DEFAULT_RETRY_AFTER_SECS: u64 = 30;
+// Response bodies are cut to this many characters in trace logs
+const TRACE_BODY_LIMIT: usize = 2000;
+const ETAG_CACHE_CAP: usize = 32;
+
+pub fn rate_limit_retry_after(error: &str) -> Option<Duration> {
+    let start = error.find(RATE_LIMITED_PREFIX)? + RATE_LIMITED_PREFIX.len();
+    let secs = error[start..]
+        .trim_start()
+        .strip_prefix("retry_after=")?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs))
+}
+
+pub fn rate_limited_error(retry_after: Duration) -> String {
+    format!(
+        "{RATE_LIMITED_PREFIX} retry_after={}",
+        retry_after.as_secs()
+    )
+}
+
+// `{"id": 1, ...}` or `{"data": {"id": 1, ...}}`; None when the backend answers
+// without a body or without an id
+fn created_entry(body: &str, request: &CreateEntryRequest) -> Option<CreatedEntry> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    let entry = value.get("data").unwrap_or(&value);
+    let field = |keys: &[&str]| keys.iter().find_map(|key| entry.get(*key));
+    let project_id = field(&["projectId", "project_id"])
+        .or_else(|| entry.get("project").and_then(|project| project.get("id")))
+        .and_then(json_i32);
+    Some(CreatedEntry {
+        id: json_i32(entry.get("id")?)?,
+        date: field(&["date"])
+            .and_then(Value::as_str)
+            .and_then(parse_date)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| request.date.clone()),
+        project_id: project_id.unwrap_or(request.project_id),
+        description: field(&["description"])
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| request.description.clone()),
+        minutes: field(&["minutes"])
+            .and_then(json_i32)
+            .unwrap_or(request.minutes),
+        is_billable: field(&["isBillable", "is_billable"])
+            .and_then(Value::as_bool)
+            .unwrap_or(request.is_billable),
+    })
+}
+
+fn json_i32(value: &Value) -> Option<i32> {
+    match value {
+        Value::Number(id) => id.as_i64().and_then(|id| i32::try_from(id).ok()),
+        Value::String(id) => id.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn check_rate_limit(response: &Response) -> Result<(), String> {
+    if response.status().as_u16() != 429 {
+        return Ok(());
+    }
+    let secs = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+    log!("Rate limited, retry after {}s", secs);
+    Err(rate_limited_error(Duration::from_secs(secs)))
+}
+
+// (ETag, body) of the last successful GET per token hash + URL, shared by every client
+// in the process so refreshes from new background clients still hit it. The oldest
+// entry goes once ETAG_CACHE_CAP URLs are stored.
+#[derive(Default)]
+struct EtagCache {
+    entries: HashMap<String, (String, String)>,
+    order: VecDeque<String>,
+}
+
+impl EtagCache {
+    fn get(&self, key: &str) -> Option<&(String, String)> {
+        self.entries.get(key)
+    }
+
+    fn insert(&mut self, key: String, value: (String, String)) {
+        if self.entries.insert(key.clone(), value).is_some() {
+            self.order.retain(|existing| *existing != key);
+        }
+        self.order.push_back(key);
+        while self.order.len() > ETAG_CACHE_CAP {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+}
+
+fn etag_cache() -> &'static Mutex<EtagCache> {
+    static CACHE: OnceLock<Mutex<EtagCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(EtagCache::default()))
+}
+
+// The raw token never sits in memory as a map key
+fn etag_cache_key(token: &str, url: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    format!("{:016x} {url}", hasher.finish())
+}
 
 pub struct ApiClient {
     pub base_url: String,
     pub token: String,
     pub client: Client,
+    create_method: Method,
+    create_path: String,
+}
+
+const CREATE_METHODS: &[&str] = &["POST", "PUT", "PATCH"];
+
+pub fn parse_create_method(raw: &str) -> Result<Method, String> {
+    let method = raw.trim().to_ascii_uppercase();
+    if !CREATE_METHODS.contains(&method.as_str()) {
+        return Err(format!(
+            "create_method invalido ({raw}); usa {}",
+            CREATE_METHODS.join(", ")
+        ));
+    }
+    Method::from_bytes(method.as_bytes()).map_err(|e| e.to_string())
 }
 
+const DEFAULT_CREATE_PATH: &str = "/time-entries";
+
+fn normalize_create_path(path: &str) -> String {
+    let path = path.trim().trim_start_matches('/');
+    if path.is_empty() {
+        DEFAULT_CREATE_PATH.to_string()
+    } else {
+        format!("/{path}")
+    }
+}
+
+// "POST /time-entries" as the configured create request would go out
+pub fn create_endpoint_label(method: &str, path: &str) -> String {
+    format!(
+        "{} {}",
+        method.trim().to_ascii_uppercase(),
+        normalize_create_path(path)
+    )
+}
+
+const PROJECT_LIST_KEYS: &[&str] = &["data", "projects", "items"];
+
 #[derive(Clone, Copy)]
 pub enum QueryStyle {
     Snake,
@@ -21,6 +183,9 @@ pub enum QueryStyle {
 
 pub struct FetchResult {
     pub days: Vec<Day>,
+    pub time_entries: Vec<TimeEntry>,
+    // Set when /projects failed and entries carry placeholder project names
+    pub projects_error: Option<String>,
 }
 
 impl ApiClient {
@@ -34,9 +199,17 @@ impl ApiClient {
             base_url: base_url.trim_end_matches('/').to_string(),
             token,
             client,
+            create_method: Method::POST,
+            create_path: DEFAULT_CREATE_PATH.to_string(),
         })
     }
 
+    pub fn with_create_endpoint(mut self, method: &str, path: &str) -> Result<Self, String> {
+        self.create_method = parse_create_method(method)?;
+        self.create_path = normalize_create_path(path);
+        Ok(self)
+    }
+
     pub fn create_time_entry(
         &self,
         date: &str,
@@ -44,12 +217,13 @@ impl ApiClient {
         description: &str,
         minutes: i32,
         is_billable: bool,
-    ) -> Result<(), String> {
-        let url = format!("{}/time-entries", self.base_url);
-        log!("POST Request URL: {}", url);
-        log!("POST Token Len: {}", self.token.len());
+    ) -> Result<Option<CreatedEntry>, String> {
+        let url = format!("{}{}", self.base_url, self.create_path);
+        log!("{} Request URL: {}", self.create_method, url);
+        log!("{} Token Len: {}", self.create_method, self.token.len());
         log!(
-            "POST Body: date={}, pid={}, desc={}, mins={}, billable={}",
+            "{} Body: date={}, pid={}, desc={}, mins={}, billable={}",
+            self.create_method,
             date,
             project_id,
             description,
@@ -67,67 +241,179 @@ impl ApiClient {
         };
 
         let body_json = serde_json::to_string(&body).map_err(|e| e.to_string())?;
-        log!("POST Body JSON: {}", body_json);
+        log!("{} Body JSON: {}", self.create_method, body_json);
 
         let response = self
-            .client
-            .post(&url)
-            .bearer_auth(&self.token)
-            .json(&body)
-            .send()
+            .send(
+                self.client
+                    .request(self.create_method.clone(), &url)
+                    .bearer_auth(&self.token)
+                    .json(&body),
+            )
             .map_err(|e| format!("Reqwest Error (builder/send): {}", e))?;
+        check_rate_limit(&response)?;
 
         let status = response.status();
-        log!("POST Response Status: {}", status);
+        log!("{} Response Status: {}", self.create_method, status);
+        let text = self.read_body(response).unwrap_or_default();
         if status.is_success() || status.as_u16() == 201 {
+            Ok(created_entry(&text, &body))
+        } else {
+            log!("{} Error Body: {}", self.create_method, text);
+            Err(format!("{} {}", status.as_u16(), text))
+        }
+    }
+
+    pub fn update_time_entry(
+        &self,
+        id: i32,
+        date: &str,
+        project_id: i32,
+        description: &str,
+        minutes: i32,
+        is_billable: bool,
+    ) -> Result<(), String> {
+        let url = format!("{}/time-entries/{}", self.base_url, id);
+        log!("PUT Request URL: {}", url);
+
+        let body = CreateEntryRequest {
+            date: date.to_string(),
+            project_id,
+            description: description.to_string(),
+            minutes,
+            is_billable,
+            tag_ids: Vec::new(),
+        };
+
+        let response = self.send(self.client.put(&url).bearer_auth(&self.token).json(&body))?;
+        check_rate_limit(&response)?;
+
+        let status = response.status();
+        log!("PUT Response Status: {}", status);
+        let text = self.read_body(response).unwrap_or_default();
+        if status.is_success() {
             Ok(())
         } else {
-            let text = response.text().unwrap_or_default();
-            log!("POST Error Body: {}", text);
             Err(format!("{} {}", status.as_u16(), text))
         }
     }
 
+    pub fn delete_time_entry(&self, id: i32) -> Result<(), String> {
+        let url = format!("{}/time-entries/{}", self.base_url, id);
+        log!("DELETE Request URL: {}", url);
+
+        let response = self.send(self.client.delete(&url).bearer_auth(&self.token))?;
+        check_rate_limit(&response)?;
+
+        let status = response.status();
+        log!("DELETE Response Status: {}", status);
+        let text = self.read_body(response).unwrap_or_default();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(format!("{} {}", status.as_u16(), text))
+        }
+    }
+
+    // Up to `concurrency` POSTs in flight; results come back in input order
+    pub fn create_time_entries(
+        &self,
+        requests: &[CreateEntryRequest],
+        concurrency: usize,
+    ) -> Vec<Result<(), String>> {
+        let workers = concurrency.clamp(1, requests.len().max(1));
+        let queue = Mutex::new(requests.iter().enumerate());
+        let (tx, rx) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                let tx = tx.clone();
+                let queue = &queue;
+                scope.spawn(move || {
+                    loop {
+                        let next = queue.lock().ok().and_then(|mut queue| queue.next());
+                        let Some((idx, request)) = next else {
+                            break;
+                        };
+                        let result = self.create_time_entry(
+                            &request.date,
+                            request.project_id,
+                            &request.description,
+                            request.minutes,
+                            request.is_billable,
+                        );
+                        let _ = tx.send((idx, result.map(|_| ())));
+                    }
+                });
+            }
+        });
+        drop(tx);
+
+        let mut results: Vec<Result<(), String>> = requests
+            .iter()
+            .map(|_| Err("sin respuesta".to_string()))
+            .collect();
+        for (idx, result) in rx {
+            results[idx] = result;
+        }
+        results
+    }
+
     pub fn fetch_days(&self, start_date: &str, end_date: &str) -> Result<FetchResult, String> {
         log!("Fetching days: {} to {}", start_date, end_date);
-        let projects = self.fetch_projects_list()?;
+        // Project names are optional: entries still load and can be relabeled later
+        let (projects, projects_error) = match self.fetch_projects_list() {
+            Ok(projects) => (projects, None),
+            Err(e) => {
+                log!("Projects unavailable while fetching days: {}", e);
+                (Vec::new(), Some(e))
+            }
+        };
         let (time_entries, _) = self.get_time_entries(start_date, end_date)?;
         let entries_count = time_entries.len();
         log!("Fetched {} entries", entries_count);
 
-        let days = crate::utils::parsing::build_days(time_entries, projects, start_date, end_date);
-        Ok(FetchResult { days })
+        let days =
+            crate::utils::parsing::build_days(time_entries.clone(), projects, start_date, end_date);
+        Ok(FetchResult {
+            days,
+            time_entries,
+            projects_error,
+        })
     }
 
     pub fn fetch_projects_list(&self) -> Result<Vec<Project>, String> {
         let url = format!("{}/projects", self.base_url);
         log!("Fetching projects from: {}", url);
-        let response = self
-            .client
-            .get(url)
-            .bearer_auth(&self.token)
-            .send()
-            .map_err(|e| e.to_string())?;
-
-        let status = response.status();
+        let (status, text) = self.send_cached_get(self.client.get(url).bearer_auth(&self.token))?;
         log!("Projects response status: {}", status);
-        let text = response.text().map_err(|e| e.to_string())?;
-
-        // Parse as Map<String, Vec<Project>>
-        let map: HashMap<String, Vec<Project>> = serde_json::from_str(&text).map_err(|e| {
-            log!("Error parsing projects JSON: {}", e);
-            format!(
-                "Error parsing projects: {} | Response start: {:.50}",
-                e, text
-            )
-        })?;
 
+        // Parse as Map<String, Vec<Project>> grouped by client
         let mut all_projects = Vec::new();
-        for (client, mut projects) in map {
-            for p in &mut projects {
-                p.client_name = client.clone();
+        match serde_json::from_str::<HashMap<String, Vec<Project>>>(&text) {
+            Ok(map)
+                if !map
+                    .keys()
+                    .any(|key| PROJECT_LIST_KEYS.contains(&key.as_str())) =>
+            {
+                for (client, mut projects) in map {
+                    for p in &mut projects {
+                        p.client_name = client.clone();
+                    }
+                    all_projects.extend(projects);
+                }
+            }
+            _ => {
+                // Fallback to flat list or {data:[...]} wrapper (no client grouping)
+                all_projects =
+                    parse_list_from_body::<Project>(&text, PROJECT_LIST_KEYS).map_err(|e| {
+                        log!("Error parsing projects JSON: {}", e);
+                        format!(
+                            "Error parsing projects: {} | Response start: {:.50}",
+                            e, text
+                        )
+                    })?;
             }
-            all_projects.extend(projects);
         }
         // Sort by Client then Name
         all_projects.sort_by(|a, b| a.client_name.cmp(&b.client_name).then(a.name.cmp(&b.name)));
@@ -135,20 +421,137 @@ impl ApiClient {
         Ok(all_projects)
     }
 
+    // Returns the unparsed body for debugging API shape mismatches
+    pub fn fetch_raw(&self, path: &str, query: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+        log!("Fetching raw from: {}", url);
+        let response = self.send(self.client.get(url).bearer_auth(&self.token).query(query))?;
+        check_rate_limit(&response)?;
+
+        let status = response.status();
+        let body = self.read_body(response)?;
+        if !status.is_success() {
+            return Err(format!("{} {}", status.as_u16(), body));
+        }
+        Ok(body)
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<Response, String> {
+        self.execute(request.build().map_err(|e| e.to_string())?)
+    }
+
+    // Every request goes through here so VARTUI_TRACE sees all of them
+    fn execute(&self, request: Request) -> Result<Response, String> {
+        let tracing = trace_enabled();
+        if tracing {
+            log!(
+                "TRACE -> {} {}",
+                request.method(),
+                self.redact(request.url().as_str())
+            );
+        }
+        let method = request.method().to_string();
+        let url = request.url().to_string();
+        if let Some(entry) = http_tape::replayed(&method, &url) {
+            return entry?.to_response();
+        }
+        let started = Instant::now();
+        let response = self.client.execute(request).map_err(|e| e.to_string())?;
+        if tracing {
+            log!(
+                "TRACE <- {} in {}ms",
+                response.status(),
+                started.elapsed().as_millis()
+            );
+        }
+        if !http_tape::is_recording() {
+            return Ok(response);
+        }
+        let entry = TapeEntry::capture(&method, &url, response)?;
+        let response = entry.to_response();
+        http_tape::record(entry);
+        response
+    }
+
+    fn read_body(&self, response: Response) -> Result<String, String> {
+        let body = response.text().map_err(|e| e.to_string())?;
+        if trace_enabled() {
+            let shown: String = body.chars().take(TRACE_BODY_LIMIT).collect();
+            let cut = if shown.len() < body.len() { "..." } else { "" };
+            log!(
+                "TRACE body ({} bytes): {}{}",
+                body.len(),
+                self.redact(&shown),
+                cut
+            );
+        }
+        Ok(body)
+    }
+
+    // Very short values would mangle unrelated text; real tokens are much longer
+    fn redact(&self, text: &str) -> String {
+        if self.token.len() < 8 {
+            return text.to_string();
+        }
+        text.replace(&self.token, "<token>")
+    }
+
+    // Sends If-None-Match when an ETag is cached; a 304 replays the cached body as a 200.
+    // Servers without ETags simply never populate the cache.
+    fn send_cached_get(&self, request: RequestBuilder) -> Result<(StatusCode, String), String> {
+        let mut request = request.build().map_err(|e| e.to_string())?;
+        let key = etag_cache_key(&self.token, request.url().as_str());
+        let cached = etag_cache()
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(&key).cloned());
+        if let Some((etag, _)) = &cached
+            && let Ok(value) = HeaderValue::from_str(etag)
+        {
+            request.headers_mut().insert(IF_NONE_MATCH, value);
+        }
+
+        let response = self.execute(request)?;
+        check_rate_limit(&response)?;
+
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            return match cached {
+                Some((_, body)) => {
+                    log!("304 Not Modified, using cached body");
+                    Ok((StatusCode::OK, body))
+                }
+                None => Err("304 sin respuesta en cache".to_string()),
+            };
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = self.read_body(response)?;
+        if status.is_success()
+            && let Some(etag) = etag
+            && let Ok(mut cache) = etag_cache().lock()
+        {
+            cache.insert(key, (etag, body.clone()));
+        }
+        Ok((status, body))
+    }
+
     fn get_time_entries(
         &self,
         start_date: &str,
         end_date: &str,
     ) -> Result<(Vec<TimeEntry>, QueryStyle), String> {
         let primary = self.get_time_entries_with_params(start_date, end_date, QueryStyle::Snake)?;
-        if should_try_alt_dates(start_date, end_date, primary.len()) {
-            if let Ok(alt) =
+        if should_try_alt_dates(start_date, end_date, primary.len())
+            && let Ok(alt) =
                 self.get_time_entries_with_params(start_date, end_date, QueryStyle::Camel)
-            {
-                if alt.len() > primary.len() {
-                    return Ok((alt, QueryStyle::Camel));
-                }
-            }
+            && alt.len() > primary.len()
+        {
+            return Ok((alt, QueryStyle::Camel));
         }
 
         Ok((primary, QueryStyle::Snake))
@@ -168,11 +571,8 @@ impl ApiClient {
             }
             QueryStyle::Camel => request.query(&[("startDate", start_date), ("endDate", end_date)]),
         };
-        let response = request.send().map_err(|error| error.to_string())?;
-
-        let status = response.status();
+        let (status, body) = self.send_cached_get(request)?;
         if !status.is_success() {
-            let body = response.text().unwrap_or_default();
             return Err(format!(
                 "{} {}",
                 status.as_u16(),
@@ -180,7 +580,6 @@ impl ApiClient {
             ));
         }
 
-        let body = response.text().unwrap_or_default();
         // Try parsing as HashMap<String, Vec<TimeEntry>>
         if let Ok(map) = serde_json::from_str::<HashMap<String, Vec<TimeEntry>>>(&body) {
             let mut all_entries = Vec::new();
@@ -229,10 +628,10 @@ fn extract_list(value: &Value, keys: &[&str]) -> Option<Value> {
         Value::Array(_) if is_object_array(value) => Some(value.clone()),
         Value::Object(map) => {
             for key in keys {
-                if let Some(list) = map.get(*key) {
-                    if is_object_array(list) {
-                        return Some(list.clone());
-                    }
+                if let Some(list) = map.get(*key)
+                    && is_object_array(list)
+                {
+                    return Some(list.clone());
                 }
             }
             for entry in map.values() {
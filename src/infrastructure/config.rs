@@ -1,11 +1,15 @@
 use crate::domain::config::AppConfig;
+use crate::infrastructure::http_tape;
 use crate::log;
 use confy;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
 
 const APP_NAME: &str = "vartui";
 
 pub fn load_config() -> AppConfig {
-    match confy::load(APP_NAME, "config") {
+    match try_load_config() {
         Ok(cfg) => {
             log!("Config loaded successfully");
             cfg
@@ -17,6 +21,28 @@ pub fn load_config() -> AppConfig {
     }
 }
 
+// Unlike `load_config`, a broken file is an error instead of the defaults
+pub fn try_load_config() -> Result<AppConfig, String> {
+    confy::load(APP_NAME, "config").map_err(|e| e.to_string())
+}
+
+// Modification time of config.toml; None if it does not exist yet
+pub fn config_modified() -> Option<SystemTime> {
+    let path = confy::get_configuration_file_path(APP_NAME, "config").ok()?;
+    fs::metadata(path).ok()?.modified().ok()
+}
+
 pub fn save_config(cfg: &AppConfig) -> Result<(), String> {
+    if http_tape::is_replaying() {
+        return Ok(());
+    }
     confy::store(APP_NAME, "config", cfg).map_err(|e| e.to_string())
 }
+
+// Directory holding config.toml; other local state files live next to it
+pub fn config_dir() -> Result<PathBuf, String> {
+    let path = confy::get_configuration_file_path(APP_NAME, "config").map_err(|e| e.to_string())?;
+    path.parent()
+        .map(|dir| dir.to_path_buf())
+        .ok_or_else(|| "ruta de config invalida".to_string())
+}
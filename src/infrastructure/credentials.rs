@@ -0,0 +1,141 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use crate::domain::config::AppConfig;
+use crate::log;
+
+pub const DEFAULT_API_BASE: &str = "https://var.elaniin.com/api";
+
+// `~/.vartui/credentials`: `key=value` lines for people who keep secrets out of
+// config.toml. Sits between the config and the VAR_* env vars in precedence.
+#[derive(Default)]
+pub struct Credentials {
+    pub base_url: Option<String>,
+    pub token: Option<String>,
+}
+
+// Read once per process; every API call resolves the token through here
+pub fn load_credentials() -> &'static Credentials {
+    static CREDENTIALS: OnceLock<Credentials> = OnceLock::new();
+    CREDENTIALS.get_or_init(read_credentials)
+}
+
+// config > credentials file > VAR_TOKEN
+pub fn resolve_token(config: &AppConfig) -> String {
+    if !config.var_token.is_empty() {
+        config.var_token.trim().to_string()
+    } else if let Some(token) = &load_credentials().token {
+        token.clone()
+    } else {
+        env::var("VAR_TOKEN")
+            .unwrap_or_default()
+            .replace('"', "")
+            .trim()
+            .to_string()
+    }
+}
+
+// config > credentials file > VAR_BASE_URL > default
+pub fn resolve_base_url(config: &AppConfig) -> String {
+    let mut base_url = if !config.base_url.is_empty() && config.base_url != DEFAULT_API_BASE {
+        config.base_url.trim().to_string()
+    } else if let Some(base_url) = &load_credentials().base_url {
+        base_url.clone()
+    } else {
+        env::var("VAR_BASE_URL")
+            .ok()
+            .map(|value| value.replace('"', "").trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| DEFAULT_API_BASE.to_string())
+    };
+
+    if base_url.ends_with('/') {
+        base_url.pop();
+    }
+
+    base_url
+}
+
+fn read_credentials() -> Credentials {
+    let Some(path) = credentials_path() else {
+        return Credentials::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(raw) => parse_credentials(&raw),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Credentials::default(),
+        // Only the path and the error; the contents are secrets
+        Err(error) => {
+            log!("Credentials file {} unreadable: {}", path.display(), error);
+            Credentials::default()
+        }
+    }
+}
+
+// VARTUI_CREDENTIALS points somewhere else
+fn credentials_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("VARTUI_CREDENTIALS").filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".vartui").join("credentials"))
+}
+
+// Blank lines and `#` comments are skipped; values may be quoted
+fn parse_credentials(raw: &str) -> Credentials {
+    let mut credentials = Credentials::default();
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value
+            .trim()
+            .trim_matches(|ch| ch == '"' || ch == '\'')
+            .trim();
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim().to_ascii_lowercase().as_str() {
+            "base_url" => credentials.base_url = Some(value.to_string()),
+            "token" | "var_token" => credentials.token = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    credentials
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_keys_comments_and_quotes() {
+        let credentials = parse_credentials(
+            "# secrets\n\nbase_url = \"https://example.test/api\"\nTOKEN='abc123'\nother=value\n",
+        );
+        assert_eq!(
+            credentials.base_url.as_deref(),
+            Some("https://example.test/api")
+        );
+        assert_eq!(credentials.token.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn accepts_var_token_and_skips_empty_or_malformed_lines() {
+        let credentials = parse_credentials("var_token=xyz\nbase_url=\"\"\nno separator\n");
+        assert_eq!(credentials.token.as_deref(), Some("xyz"));
+        assert_eq!(credentials.base_url, None);
+    }
+
+    #[test]
+    fn later_lines_override_earlier_ones() {
+        let credentials = parse_credentials("token=first\ntoken=second\n");
+        assert_eq!(credentials.token.as_deref(), Some("second"));
+        assert_eq!(parse_credentials("").token, None);
+    }
+}
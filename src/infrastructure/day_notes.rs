@@ -0,0 +1,34 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::infrastructure::config::config_dir;
+use crate::infrastructure::http_tape;
+
+// Local-only day annotations ("WFH", "medio dia enfermo"), keyed by YYYY-MM-DD
+const DAY_NOTES_FILE: &str = "day_notes.json";
+
+fn day_notes_path() -> Result<PathBuf, String> {
+    Ok(config_dir()?.join(DAY_NOTES_FILE))
+}
+
+// A missing or unreadable file is treated as empty
+pub fn load_day_notes() -> BTreeMap<String, String> {
+    day_notes_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_day_notes(notes: &BTreeMap<String, String>) -> Result<(), String> {
+    if http_tape::is_replaying() {
+        return Ok(());
+    }
+    let path = day_notes_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(notes).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())
+}
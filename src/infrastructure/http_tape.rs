@@ -0,0 +1,143 @@
+use reqwest::blocking::Response;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+
+// Only the headers ApiClient reads back from a response
+const KEPT_HEADERS: &[&str] = &["etag", "retry-after"];
+
+// One API exchange as the MCP recorder stores it. Requests are matched on
+// method + URL; the bearer token never appears here.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TapeEntry {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl TapeEntry {
+    pub fn capture(method: &str, url: &str, response: Response) -> Result<Self, String> {
+        let status = response.status().as_u16();
+        let headers = KEPT_HEADERS
+            .iter()
+            .filter_map(|name| {
+                let value = response.headers().get(*name)?.to_str().ok()?;
+                Some((name.to_string(), value.to_string()))
+            })
+            .collect();
+        let body = response.text().map_err(|e| e.to_string())?;
+        Ok(Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            status,
+            headers,
+            body,
+        })
+    }
+
+    pub fn to_response(&self) -> Result<Response, String> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder
+            .body(self.body.clone())
+            .map(Response::from)
+            .map_err(|e| e.to_string())
+    }
+
+    fn key(&self) -> String {
+        tape_key(&self.method, &self.url)
+    }
+}
+
+#[derive(Default)]
+enum Tape {
+    #[default]
+    Off,
+    Recording(Vec<TapeEntry>),
+    // Each method + URL answers its recorded responses in order
+    Replaying(HashMap<String, VecDeque<TapeEntry>>),
+}
+
+fn tape() -> &'static Mutex<Tape> {
+    static TAPE: OnceLock<Mutex<Tape>> = OnceLock::new();
+    TAPE.get_or_init(|| Mutex::new(Tape::Off))
+}
+
+fn tape_key(method: &str, url: &str) -> String {
+    format!("{method} {url}")
+}
+
+pub fn start_recording() {
+    if let Ok(mut tape) = tape().lock() {
+        *tape = Tape::Recording(Vec::new());
+    }
+}
+
+pub fn is_recording() -> bool {
+    tape()
+        .lock()
+        .is_ok_and(|tape| matches!(*tape, Tape::Recording(_)))
+}
+
+// Replay also keeps config.toml and the day notes untouched
+pub fn is_replaying() -> bool {
+    tape()
+        .lock()
+        .is_ok_and(|tape| matches!(*tape, Tape::Replaying(_)))
+}
+
+pub fn record(entry: TapeEntry) {
+    if let Ok(mut tape) = tape().lock()
+        && let Tape::Recording(entries) = &mut *tape
+    {
+        entries.push(entry);
+    }
+}
+
+// Exchanges recorded since the last call; background loads may land in a later batch
+pub fn drain_recorded() -> Vec<TapeEntry> {
+    match tape().lock() {
+        Ok(mut tape) => match &mut *tape {
+            Tape::Recording(entries) => std::mem::take(entries),
+            _ => Vec::new(),
+        },
+        Err(_) => Vec::new(),
+    }
+}
+
+// From here on no request reaches the network
+pub fn start_replay(entries: Vec<TapeEntry>) {
+    let mut recorded: HashMap<String, VecDeque<TapeEntry>> = HashMap::new();
+    for entry in entries {
+        recorded.entry(entry.key()).or_default().push_back(entry);
+    }
+    if let Ok(mut tape) = tape().lock() {
+        *tape = Tape::Replaying(recorded);
+    }
+}
+
+pub fn stop() {
+    if let Ok(mut tape) = tape().lock() {
+        *tape = Tape::Off;
+    }
+}
+
+// None outside replay; a request the recording never made is an error, not a live call
+pub fn replayed(method: &str, url: &str) -> Option<Result<TapeEntry, String>> {
+    let mut tape = tape().lock().ok()?;
+    let Tape::Replaying(recorded) = &mut *tape else {
+        return None;
+    };
+    let key = tape_key(method, url);
+    Some(
+        recorded
+            .get_mut(&key)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| format!("replay: sin respuesta grabada para {key}")),
+    )
+}
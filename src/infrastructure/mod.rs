@@ -1,2 +1,8 @@
 pub mod api_client;
+pub mod clipboard;
 pub mod config;
+pub mod credentials;
+pub mod day_notes;
+pub mod http_tape;
+pub mod projects_cache;
+pub mod signals;
@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::models::Project;
+use crate::infrastructure::config::config_dir;
+use crate::infrastructure::http_tape;
+
+const CACHE_FILE: &str = "projects_cache.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedProject {
+    pub id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub client_name: String,
+}
+
+impl From<&Project> for CachedProject {
+    fn from(project: &Project) -> Self {
+        Self {
+            id: project.id,
+            name: project.name.clone(),
+            client_name: project.client_name.clone(),
+        }
+    }
+}
+
+impl From<CachedProject> for Project {
+    fn from(project: CachedProject) -> Self {
+        Self {
+            id: project.id,
+            name: project.name,
+            client_name: project.client_name,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CacheDiff {
+    pub added: usize,
+    pub removed: usize,
+}
+
+fn cache_path() -> Result<PathBuf, String> {
+    Ok(config_dir()?.join(CACHE_FILE))
+}
+
+// A missing or unreadable cache is treated as empty
+fn load_projects_cache() -> Vec<CachedProject> {
+    cache_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+// Projects from the last successful fetch, so the TUI picker works before the API answers
+pub fn cached_projects() -> Vec<Project> {
+    load_projects_cache()
+        .into_iter()
+        .map(Project::from)
+        .collect()
+}
+
+// Overwrites the cache with `projects` and reports how it changed by id
+pub fn replace_projects_cache(projects: &[Project]) -> Result<CacheDiff, String> {
+    let fresh: Vec<CachedProject> = projects.iter().map(CachedProject::from).collect();
+    let diff = cache_diff(&load_projects_cache(), &fresh);
+    if http_tape::is_replaying() {
+        return Ok(diff);
+    }
+
+    let path = cache_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    }
+    let contents = serde_json::to_string_pretty(&fresh).map_err(|e| e.to_string())?;
+    fs::write(&path, contents).map_err(|e| e.to_string())?;
+    Ok(diff)
+}
+
+fn cache_diff(previous: &[CachedProject], fresh: &[CachedProject]) -> CacheDiff {
+    let previous: HashSet<i32> = previous.iter().map(|project| project.id).collect();
+    let current: HashSet<i32> = fresh.iter().map(|project| project.id).collect();
+    CacheDiff {
+        added: current.difference(&previous).count(),
+        removed: previous.difference(&current).count(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(id: i32, name: &str) -> CachedProject {
+        CachedProject {
+            id,
+            name: name.to_string(),
+            client_name: String::new(),
+        }
+    }
+
+    #[test]
+    fn diff_counts_added_and_removed_ids() {
+        let previous = [cached(1, "A"), cached(2, "B"), cached(3, "C")];
+        let fresh = [cached(2, "B renombrado"), cached(3, "C"), cached(4, "D")];
+        assert_eq!(
+            cache_diff(&previous, &fresh),
+            CacheDiff {
+                added: 1,
+                removed: 1
+            }
+        );
+        assert_eq!(
+            cache_diff(&[], &fresh),
+            CacheDiff {
+                added: 3,
+                removed: 0
+            }
+        );
+    }
+
+    #[test]
+    fn cached_projects_roundtrip_through_json() {
+        let project = Project {
+            id: 7,
+            name: "Interno".to_string(),
+            client_name: "ACME".to_string(),
+        };
+        let json = serde_json::to_string(&[CachedProject::from(&project)]).expect("serializes");
+        let restored: Vec<CachedProject> = serde_json::from_str(&json).expect("parses");
+        let restored = Project::from(restored[0].clone());
+        assert_eq!(
+            (
+                restored.id,
+                restored.name.as_str(),
+                restored.client_name.as_str()
+            ),
+            (7, "Interno", "ACME")
+        );
+
+        // Caches written before client_name existed still load
+        let old: Vec<CachedProject> =
+            serde_json::from_str(r#"[{"id": 1, "name": "A"}]"#).expect("parses");
+        assert_eq!(old[0].client_name, "");
+    }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+// Set when SIGTERM/SIGHUP arrive so the TUI loop can exit and restore the terminal.
+pub fn shutdown_flag() -> Arc<AtomicBool> {
+    let flag = Arc::new(AtomicBool::new(false));
+    register(&flag);
+    flag
+}
+
+#[cfg(unix)]
+fn register(flag: &Arc<AtomicBool>) {
+    use crate::log;
+    use signal_hook::consts::{SIGHUP, SIGTERM};
+
+    for signal in [SIGTERM, SIGHUP] {
+        if let Err(e) = signal_hook::flag::register(signal, Arc::clone(flag)) {
+            log!("Failed to register signal {}: {}", signal, e);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn register(_flag: &Arc<AtomicBool>) {}
@@ -1,27 +1,52 @@
+// serde_json::json! for the MCP tools/list schema nests deeper than the default limit
+#![recursion_limit = "256"]
+
 mod application;
 mod domain;
 mod infrastructure;
 mod ui;
 mod utils;
 
+use chrono::Local;
 use crossterm::event::{self, Event};
 use std::env;
+use std::fmt;
 use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
-use crate::application::app::App;
+use crate::application::app::{App, InputMode};
 use crate::application::cli::{api_help, run_api};
 use crate::application::input::handle_key;
 use crate::application::mcp::{mcp_help, run_mcp};
-use crate::ui::tui::{restore_terminal, setup_terminal};
+use crate::infrastructure::config::load_config;
+use crate::infrastructure::signals::shutdown_flag;
+use crate::ui::tui::{render_to_text, restore_terminal, setup_terminal};
 use crate::ui::ui;
+use crate::utils::i18n::{Text, tr};
+
+// `main` prints a returned error with Debug; this keeps it to the plain message
+struct ExitError(String);
+
+impl fmt::Debug for ExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
 
-fn main() -> io::Result<()> {
+impl From<io::Error> for ExitError {
+    fn from(error: io::Error) -> Self {
+        Self(error.to_string())
+    }
+}
+
+fn main() -> Result<(), ExitError> {
     dotenvy::dotenv().ok();
 
     let args: Vec<String> = env::args().collect();
     match args.get(1).map(String::as_str) {
-        None | Some("tui") => run_tui(),
+        None => Ok(run_tui(&[])?),
+        Some("tui") => Ok(run_tui(&args[2..])?),
         Some("api") => {
             if let Err(error) = run_api(&args[2..]) {
                 eprintln!("{error}");
@@ -29,6 +54,13 @@ fn main() -> io::Result<()> {
             }
             Ok(())
         }
+        Some("render") => {
+            if let Err(error) = run_render(&args[2..]) {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+            Ok(())
+        }
         Some("mcp") => {
             if let Err(error) = run_mcp(&args[2..]) {
                 eprintln!("{error}");
@@ -48,17 +80,81 @@ fn main() -> io::Result<()> {
     }
 }
 
-fn run_tui() -> io::Result<()> {
+fn run_tui(args: &[String]) -> io::Result<()> {
+    let mut start_adding = false;
+    for arg in args {
+        match arg.as_str() {
+            "--add" => start_adding = true,
+            other => {
+                eprintln!("Flag desconocida para tui: {other}\n");
+                print_help(&env::args().next().unwrap_or_default());
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let mut app = App::new();
+    // Without a token App::new already opened the config modal; keep that first
+    if start_adding && app.input_mode == InputMode::Normal {
+        app.open_add_entry_for(Local::now().format("%Y-%m-%d").to_string());
+    }
+
+    let shutdown = shutdown_flag();
     let mut terminal = setup_terminal()?;
-    let result = run_app(&mut terminal, App::new());
+    let result = run_app(&mut terminal, app, &shutdown);
     restore_terminal(&mut terminal)?;
     result
 }
 
+// Headless single-frame render for bug reports about layout
+fn run_render(args: &[String]) -> Result<(), String> {
+    let mut width: u16 = 120;
+    let mut height: u16 = 40;
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            flag @ ("--width" | "--height") => {
+                i += 1;
+                let value = args
+                    .get(i)
+                    .ok_or_else(|| format!("Falta valor para {flag}"))?;
+                let parsed = value
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|value| *value >= 10)
+                    .ok_or_else(|| format!("Valor invalido para {flag}: {value} (minimo 10)"))?;
+                if flag == "--width" {
+                    width = parsed;
+                } else {
+                    height = parsed;
+                }
+            }
+            other => {
+                return Err(format!(
+                    "Flag desconocida para render: {other}\n\n{RENDER_HELP}"
+                ));
+            }
+        }
+        i += 1;
+    }
+
+    let mut app = App::new_headless();
+    app.wait_background_load(Duration::from_secs(10));
+    println!("{}", render_to_text(&mut app, width, height));
+    Ok(())
+}
+
+const RENDER_HELP: &str = "Uso:\n  render [--width <COLUMNAS>] [--height <FILAS>]";
+
 fn print_help(bin: &str) {
+    let lang = load_config().language;
     println!(
-        "Uso:\n  {bin} tui\n  {bin} api <subcomando>\n  {bin} mcp\n\nSubcomandos API:\n{}\n\nSubcomandos MCP:\n{}",
+        "{}\n  {bin} tui [--add]\n  {bin} api <subcomando>\n  {bin} mcp\n  {bin} render [--width <COLUMNAS>] [--height <FILAS>]\n\n{}\n{}\n\n{}\n{}",
+        tr(lang, Text::HelpUsage),
+        tr(lang, Text::HelpApiSubcommands),
         api_help(),
+        tr(lang, Text::HelpMcpSubcommands),
         mcp_help()
     );
 }
@@ -66,18 +162,26 @@ fn print_help(bin: &str) {
 fn run_app(
     terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
     mut app: App,
+    shutdown: &AtomicBool,
 ) -> io::Result<()> {
     loop {
+        // SIGTERM/SIGHUP: leave the loop so the caller restores the terminal
+        if shutdown.load(Ordering::Relaxed) {
+            app.flush_theme_save();
+            return Ok(());
+        }
+
         terminal.draw(|frame| ui(frame, &mut app))?;
 
         app.check_background_load();
+        app.tick();
 
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                if handle_key(&mut app, key.code, key.modifiers) {
-                    return Ok(());
-                }
-            }
+        if event::poll(Duration::from_millis(250))?
+            && let Event::Key(key) = event::read()?
+            && handle_key(&mut app, key.code, key.modifiers)
+        {
+            app.flush_theme_save();
+            return Ok(());
         }
     }
 }
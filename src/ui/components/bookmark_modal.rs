@@ -0,0 +1,43 @@
+use ratatui::{
+    Frame,
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem},
+};
+
+use crate::application::app::App;
+use crate::ui::helpers::centered_rect;
+use crate::ui::theme::palette_from_config;
+use crate::utils::i18n::{Text, tr};
+
+pub fn render_bookmark_picker(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(50, 40, frame.area());
+    let palette = palette_from_config(&app.config);
+
+    let items: Vec<ListItem> = app
+        .bookmark_names()
+        .into_iter()
+        .map(|name| {
+            let range = app
+                .config
+                .range_bookmarks
+                .get(&name)
+                .cloned()
+                .unwrap_or_default();
+            ListItem::new(format!("{}  {}", name, range))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(tr(app.config.language, Text::BookmarksTitle))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette.accent))
+                .style(Style::default().bg(palette.bg).fg(palette.fg)),
+        )
+        .highlight_style(Style::default().bg(palette.accent).fg(palette.bg))
+        .highlight_symbol("-> ");
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut app.bookmark_state);
+}
@@ -1,3 +1,5 @@
+use std::env;
+
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
@@ -5,9 +7,11 @@ use ratatui::{
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
 
-use crate::application::app::{App, ConfigField};
+use crate::application::app::{App, ConfigField, RANGE_CUSTOM_LABEL, RANGE_OPTIONS};
+use crate::domain::config::AppConfig;
 use crate::ui::helpers::centered_rect;
-use crate::ui::theme::{THEME_CATALOG, palette_with_override, resolve_theme_name};
+use crate::ui::theme::{THEME_CATALOG, palette_with_override, resolve_theme_slug_with_override};
+use crate::utils::i18n::{Text, tr, tr_args};
 use crate::utils::version::build_version;
 
 pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
@@ -21,9 +25,10 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
     let preview_theme = app.config_form.as_ref().map(|form| form.theme.as_str());
     let palette = palette_with_override(&app.config, preview_theme);
     let version = build_version();
+    let lang = app.config.language;
 
     let block = Block::default()
-        .title(format!("Configuracion Local [{}]", version))
+        .title(tr_args(lang, Text::ConfigTitle, &[version]))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(palette.accent))
         .style(Style::default().bg(palette.bg).fg(palette.fg));
@@ -46,9 +51,11 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
         .add_modifier(Modifier::BOLD);
     let style_normal = Style::default().fg(palette.fg);
 
-    let (show_theme_dropdown, current_theme) = {
+    let mask_token = always_mask_token(&app.config);
+    let (show_theme_dropdown, show_range_dropdown, current_theme) = {
         let form = app.config_form.as_ref().unwrap();
         let show_theme_dropdown = form.focused == ConfigField::Theme;
+        let show_range_dropdown = form.focused == ConfigField::DefaultRange;
         let current_theme = form.theme.clone();
 
         let render_field =
@@ -60,7 +67,10 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
                     style_normal
                 };
 
-                let display_value = if field == ConfigField::Token && !is_focused {
+                let display_value = if field == ConfigField::Token && is_focused && mask_token {
+                    // Edits still hit the real value; only the rendering is masked
+                    "*".repeat(value.chars().count())
+                } else if field == ConfigField::Token && !is_focused {
                     if value.len() > 4 {
                         format!("...{}", &value[value.len() - 4..])
                     } else {
@@ -93,42 +103,44 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
             ConfigField::BaseUrl,
             chunks[1],
         );
+        let range_title = if form.is_custom_range() {
+            tr(lang, Text::DefaultRangeCustomTitle)
+        } else {
+            tr(lang, Text::DefaultRangeListTitle)
+        };
         render_field(
             frame,
-            "Rango Default",
+            range_title,
             &form.default_range,
             ConfigField::DefaultRange,
             chunks[2],
         );
         render_field(
             frame,
-            "Tema (lista)",
+            tr(lang, Text::ThemeFieldTitle),
             &form.theme,
             ConfigField::Theme,
             chunks[3],
         );
 
-        let theme_preview = resolve_theme_name(&form.theme).slug();
+        let theme_preview = resolve_theme_slug_with_override(&app.config, Some(&form.theme));
         let theme_catalog = THEME_CATALOG.join(", ");
-        let help_text = format!(
-            "Version build: {}\n\
-             Formatos de rango: AUTO | AUTO-WEEK | AUTO-MONTH | YYYY-MM-DD..YYYY-MM-DD\n\
-             Tema actual: {} (aplicado: {})\n\
-             Catalogo: {}\n\
-             Tab/Shift+Tab: campo | Up/Down: tema (cuando Tema esta activo) | Ctrl+U: limpiar | Ctrl+R: restablecer | Enter: guardar | Esc: cancelar{}",
-            version,
-            form.theme,
-            theme_preview,
-            theme_catalog,
-            if app.status.contains("Error")
-                || app.status.contains("guardada")
-                || app.status.contains("restablecida")
-                || app.status.contains("No hay token")
-            {
-                format!("\n{}", app.status)
-            } else {
-                String::new()
-            }
+        let notice = form
+            .notice
+            .as_ref()
+            .map(|notice| format!("\n{notice}"))
+            .unwrap_or_default();
+        let help_text = tr_args(
+            lang,
+            Text::ConfigHelp,
+            &[
+                version,
+                &app.date_range.label(),
+                &form.theme,
+                theme_preview,
+                &theme_catalog,
+                &notice,
+            ],
         );
 
         frame.render_widget(
@@ -141,16 +153,52 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
         let (cursor_rect, text_len) = match form.focused {
             ConfigField::Token => (chunks[0], form.token.chars().count()),
             ConfigField::BaseUrl => (chunks[1], form.base_url.chars().count()),
-            ConfigField::DefaultRange => (chunks[2], form.default_range.chars().count()),
+            ConfigField::DefaultRange if form.is_custom_range() => {
+                (chunks[2], form.default_range.chars().count())
+            }
+            ConfigField::DefaultRange => (Rect::default(), 0),
             ConfigField::Theme => (chunks[3], form.theme.chars().count()),
         };
 
         if cursor_rect.width > 0 {
             frame.set_cursor_position((cursor_rect.x + 1 + text_len as u16, cursor_rect.y + 1));
         }
-        (show_theme_dropdown, current_theme)
+        (show_theme_dropdown, show_range_dropdown, current_theme)
     };
 
+    if show_range_dropdown {
+        let dropdown_area = Rect {
+            x: chunks[2].x,
+            y: chunks[2].y + 3,
+            width: chunks[2].width,
+            height: RANGE_OPTIONS.len() as u16 + 3,
+        };
+
+        let items: Vec<ListItem> = RANGE_OPTIONS
+            .iter()
+            .chain(std::iter::once(&RANGE_CUSTOM_LABEL))
+            .map(|option| ListItem::new(option.to_string()))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(tr(lang, Text::RangesTitle))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(palette.accent))
+                    .style(Style::default().bg(palette.selection).fg(palette.fg)),
+            )
+            .highlight_style(Style::default().bg(palette.accent).fg(palette.bg))
+            .highlight_symbol("-> ");
+
+        frame.render_widget(Clear, dropdown_area);
+        frame.render_stateful_widget(
+            list,
+            dropdown_area,
+            &mut app.config_form.as_mut().unwrap().range_list_state,
+        );
+    }
+
     if show_theme_dropdown {
         let dropdown_area = Rect {
             x: chunks[3].x,
@@ -170,7 +218,7 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
         let list = List::new(items)
             .block(
                 Block::default()
-                    .title("Temas")
+                    .title(tr(lang, Text::ThemesTitle))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(palette.accent))
                     .style(Style::default().bg(palette.selection).fg(palette.fg)),
@@ -186,3 +234,14 @@ pub fn render_config_modal(frame: &mut Frame, app: &mut App) {
         );
     }
 }
+
+// VARTUI_MASK_TOKEN overrides the config flag
+fn always_mask_token(config: &AppConfig) -> bool {
+    match env::var("VARTUI_MASK_TOKEN") {
+        Ok(value) => matches!(
+            value.trim().to_ascii_lowercase().as_str(),
+            "1" | "true" | "yes"
+        ),
+        Err(_) => config.always_mask_token,
+    }
+}
@@ -2,21 +2,29 @@ use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
 
-use crate::application::app::{App, FormField};
-use crate::ui::helpers::centered_rect;
+use crate::application::app::{App, FormField, entry_request_issues, is_project_id_query};
+use crate::infrastructure::api_client::create_endpoint_label;
+use crate::ui::helpers::{centered_rect, display_client};
 use crate::ui::theme::palette_from_config;
+use crate::utils::i18n::{Text, tr, tr_args};
 
 pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
     let area = centered_rect(60, 50, frame.area());
     let palette = palette_from_config(&app.config);
+    let lang = app.config.language;
 
     frame.render_widget(Clear, area);
 
+    let title = match app.entry_form.as_ref().and_then(|form| form.editing_id) {
+        Some(id) => tr_args(lang, Text::EditEntryTitle, &[&id.to_string()]),
+        None => tr(lang, Text::NewEntryTitle).to_string(),
+    };
     let block = Block::default()
-        .title("Nuevo Registro")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(Style::default().fg(palette.accent))
         .style(Style::default().bg(palette.bg).fg(palette.fg));
@@ -65,28 +73,47 @@ pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
 
         render_field(
             frame,
-            "Fecha (YYYY-MM-DD)",
+            tr(lang, Text::DateFieldTitle),
             &form.date,
             FormField::Date,
             chunks[0],
         );
+        let project_title = if form.selected_project.is_some() {
+            tr(lang, Text::ProjectFieldSelected)
+        } else if app.projects_pending() {
+            tr(lang, Text::ProjectFieldLoading)
+        } else if is_project_id_query(&form.project_search) {
+            tr(lang, Text::ProjectFieldById)
+        } else {
+            tr(lang, Text::ProjectFieldSearch)
+        };
         render_field(
             frame,
-            "Proyecto (Busca...)",
+            project_title,
             &form.project_search,
             FormField::ProjectId,
             chunks[1],
         );
         render_field(
             frame,
-            "Descripcion",
+            tr(lang, Text::DescriptionFieldTitle),
             &form.description,
             FormField::Description,
             chunks[2],
         );
+        let presets_hint = minute_presets_hint(&app.config.minute_presets);
+        let minutes_title = if form.focused == FormField::Minutes {
+            tr_args(
+                lang,
+                Text::DurationFieldFocused,
+                &[&app.config.minutes_step.to_string(), &presets_hint],
+            )
+        } else {
+            tr(lang, Text::DurationFieldTitle).to_string()
+        };
         render_field(
             frame,
-            "Duracion (HH:MM)",
+            &minutes_title,
             &form.minutes,
             FormField::Minutes,
             chunks[3],
@@ -99,11 +126,11 @@ pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
             style_normal
         };
         let billable_symbol = if form.is_billable { "[x]" } else { "[ ]" };
-        let billable = Paragraph::new(format!("{} Es facturable (espacio)", billable_symbol))
+        let billable = Paragraph::new(tr_args(lang, Text::BillableCheckbox, &[billable_symbol]))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Opciones")
+                    .title(tr(lang, Text::OptionsTitle))
                     .border_style(billable_style)
                     .style(Style::default().bg(palette.bg)),
             )
@@ -111,7 +138,7 @@ pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
         frame.render_widget(billable, chunks[4]);
 
         if form.focused == FormField::ProjectId && !form.filtered_indices.is_empty() {
-            dropdown_info = Some((chunks[1], form.filtered_indices.clone()));
+            dropdown_info = Some((chunks[1], form.filtered_indices.clone(), form.recent_count));
         }
 
         let (cursor_rect, text_len) = match form.focused {
@@ -127,22 +154,53 @@ pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
         }
     }
 
-    if let Some((area_ref, indices)) = dropdown_info {
+    if let Some((area_ref, indices, recent_count)) = dropdown_info {
+        // Recent projects come first, closed by a separator line when more follow
+        let has_separator = recent_count > 0 && indices.len() > recent_count;
         let dropdown_area = Rect {
             x: area_ref.x,
             y: area_ref.y + 3,
             width: area_ref.width,
-            height: 10.min(indices.len() as u16 + 2),
+            height: 10.min(indices.len() as u16 + 2 + u16::from(has_separator)),
         };
+        let separator = "─".repeat(area_ref.width.saturating_sub(2) as usize);
 
         let items: Vec<ListItem> = indices
             .iter()
             .filter_map(|&idx| app.projects.get(idx))
-            .map(|project| {
-                ListItem::new(format!(
-                    "{} - {} [{}]",
-                    project.id, project.name, project.client_name
-                ))
+            .enumerate()
+            .map(|(position, project)| {
+                let marker = if position < recent_count { "★ " } else { "" };
+                let client = format!("[{}]", display_client(&app.config, &project.client_name));
+                // Same name twice: the client is what tells them apart
+                let line = if app.project_name_count(project) > 1 {
+                    Line::from(vec![
+                        Span::raw(format!("{}{} - {} ", marker, project.id, project.name)),
+                        Span::styled(
+                            client,
+                            Style::default()
+                                .fg(palette.warning)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            tr(lang, Text::DuplicateProjectName),
+                            Style::default().fg(palette.warning),
+                        ),
+                    ])
+                } else {
+                    Line::from(format!(
+                        "{}{} - {} {}",
+                        marker, project.id, project.name, client
+                    ))
+                };
+                if has_separator && position + 1 == recent_count {
+                    ListItem::new(vec![
+                        line,
+                        Line::styled(separator.clone(), Style::default().fg(palette.muted)),
+                    ])
+                } else {
+                    ListItem::new(line)
+                }
             })
             .collect();
 
@@ -163,10 +221,80 @@ pub fn render_add_entry_modal(frame: &mut Frame, app: &mut App) {
         );
     }
 
+    let mut submit_hint = if app.config.enter_submits_on_last {
+        tr(lang, Text::SubmitEnterHint)
+    } else {
+        tr(lang, Text::SubmitCtrlSHint)
+    }
+    .to_string();
+    if app
+        .entry_form
+        .as_ref()
+        .is_some_and(|form| form.submit_failed)
+    {
+        submit_hint.push_str(tr(lang, Text::RetryHint));
+    }
     frame.render_widget(
-        Paragraph::new("Tab: siguiente | Shift+Tab: anterior | Enter: crear | Esc: cancelar")
+        Paragraph::new(tr_args(lang, Text::EntryFormHelp, &[&submit_hint]))
             .style(Style::default().fg(palette.muted))
             .alignment(ratatui::layout::Alignment::Center),
         chunks[5],
     );
+
+    if app.show_entry_preview {
+        render_entry_preview(frame, app);
+    }
+}
+
+fn render_entry_preview(frame: &mut Frame, app: &App) {
+    let Some(request) = app.entry_request() else {
+        return;
+    };
+    let palette = palette_from_config(&app.config);
+    let area = centered_rect(50, 60, frame.area());
+
+    let json = serde_json::to_string_pretty(&request).unwrap_or_default();
+    let mut lines: Vec<Line> = json
+        .lines()
+        .map(|line| Line::raw(line.to_string()))
+        .collect();
+    let issues = entry_request_issues(app.config.language, &request);
+    if !issues.is_empty() {
+        lines.push(Line::raw(""));
+        for issue in issues {
+            lines.push(Line::styled(
+                format!("! {}", issue),
+                Style::default().fg(palette.error),
+            ));
+        }
+    }
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(tr_args(
+                    app.config.language,
+                    Text::PayloadPreviewTitle,
+                    &[&create_endpoint_label(
+                        &app.config.create_method,
+                        &app.config.create_path,
+                    )],
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette.info))
+                .style(Style::default().bg(palette.bg).fg(palette.fg)),
+        ),
+        area,
+    );
+}
+
+fn minute_presets_hint(presets: &[u32]) -> String {
+    presets
+        .iter()
+        .take(9)
+        .enumerate()
+        .map(|(index, minutes)| format!("Alt+{}: {}", index + 1, minutes))
+        .collect::<Vec<_>>()
+        .join(" ")
 }
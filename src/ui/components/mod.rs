@@ -1,2 +1,5 @@
+pub mod bookmark_modal;
 pub mod config_modal;
 pub mod entry_modal;
+pub mod search_modal;
+pub mod template_modal;
@@ -0,0 +1,57 @@
+use ratatui::{
+    Frame,
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem},
+};
+
+use crate::application::app::App;
+use crate::ui::helpers::{centered_rect, display_note, truncate_with_ellipsis};
+use crate::ui::theme::palette_from_config;
+use crate::utils::i18n::{Text, tr, tr_args};
+use crate::utils::parsing::format_duration;
+
+pub fn render_search_results(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(70, 60, frame.area());
+    let palette = palette_from_config(&app.config);
+    let hhmm = app.config.uses_hhmm();
+
+    let items: Vec<ListItem> = app
+        .search_results
+        .iter()
+        .filter_map(|&(day_idx, entry_idx)| {
+            let day = app.days.get(day_idx)?;
+            let entry = day.entries.get(entry_idx)?;
+            Some(ListItem::new(format!(
+                "{}  {:<24} {:>5}  {}",
+                day.date,
+                truncate_with_ellipsis(&entry.project, 24),
+                format_duration(entry.hours, hhmm),
+                display_note(&app.config, &entry.note)
+            )))
+        })
+        .collect();
+
+    let title = if app.input.trim().is_empty() {
+        tr(app.config.language, Text::SearchTitle).to_string()
+    } else {
+        tr_args(
+            app.config.language,
+            Text::SearchResultsTitle,
+            &[&items.len().to_string()],
+        )
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette.info))
+                .style(Style::default().bg(palette.bg).fg(palette.fg)),
+        )
+        .highlight_style(Style::default().bg(palette.accent).fg(palette.bg))
+        .highlight_symbol("-> ");
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut app.search_state);
+}
@@ -0,0 +1,53 @@
+use ratatui::{
+    Frame,
+    style::Style,
+    widgets::{Block, Borders, Clear, List, ListItem},
+};
+
+use crate::application::app::App;
+use crate::ui::helpers::centered_rect;
+use crate::ui::theme::palette_from_config;
+use crate::utils::i18n::{Text, tr};
+
+pub fn render_template_picker(frame: &mut Frame, app: &mut App) {
+    let area = centered_rect(50, 40, frame.area());
+    let palette = palette_from_config(&app.config);
+
+    let items: Vec<ListItem> = app
+        .config
+        .entry_templates
+        .iter()
+        .map(|template| {
+            let project = app
+                .projects
+                .iter()
+                .find(|project| project.id == template.project_id)
+                .map_or_else(|| template.project_id.to_string(), |p| p.name.clone());
+            ListItem::new(format!(
+                "{}  {} · {} min{}",
+                template.name,
+                project,
+                template.minutes,
+                if template.billable {
+                    ""
+                } else {
+                    tr(app.config.language, Text::TemplateNonBillable)
+                }
+            ))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(tr(app.config.language, Text::TemplatesTitle))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(palette.accent))
+                .style(Style::default().bg(palette.bg).fg(palette.fg)),
+        )
+        .highlight_style(Style::default().bg(palette.accent).fg(palette.bg))
+        .highlight_symbol("-> ");
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut app.template_state);
+}
@@ -1,5 +1,10 @@
+use std::env;
+use std::sync::OnceLock;
+
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+use crate::domain::config::AppConfig;
+
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -19,3 +24,103 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+pub fn top_right_rect(width: u16, height: u16, r: Rect) -> Rect {
+    let width = width.min(r.width);
+    let height = height.min(r.height);
+    Rect {
+        x: r.x + r.width - width,
+        y: r.y,
+        width,
+        height,
+    }
+}
+
+pub fn truncate_with_ellipsis(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        return value.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut out: String = value.chars().take(width - 1).collect();
+    out.push('…');
+    out
+}
+
+const BAR_PARTIALS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+// Fixed-width bar with eighth-block precision, padded with spaces
+pub fn duration_bar(value: f32, max: f32, width: usize) -> String {
+    if width == 0 {
+        return String::new();
+    }
+    let ratio = if max > 0.0 {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let eighths = (ratio * (width * 8) as f32).round() as usize;
+    let full = eighths / 8;
+    let remainder = eighths % 8;
+
+    let mut bar = "█".repeat(full);
+    if remainder > 0 {
+        bar.push(BAR_PARTIALS[remainder - 1]);
+    }
+    let used = full + usize::from(remainder > 0);
+    bar.push_str(&" ".repeat(width - used));
+    bar
+}
+
+// Screenshot-safe placeholders; only what is drawn changes, never the data
+pub fn display_client<'a>(config: &AppConfig, client: &'a str) -> &'a str {
+    if hides_clients(config) {
+        "[cliente]"
+    } else {
+        client
+    }
+}
+
+// Entry descriptions and the local day notes alike
+pub fn display_note<'a>(config: &AppConfig, note: &'a str) -> &'a str {
+    if hides_notes(config) { "[nota]" } else { note }
+}
+
+// The env overrides are read once; rendering runs for every row on every frame
+fn hides_clients(config: &AppConfig) -> bool {
+    static OVERRIDE: OnceLock<Option<bool>> = OnceLock::new();
+    OVERRIDE
+        .get_or_init(|| env_flag("VARTUI_HIDE_CLIENTS"))
+        .unwrap_or(config.hide_client_names)
+}
+
+fn hides_notes(config: &AppConfig) -> bool {
+    static OVERRIDE: OnceLock<Option<bool>> = OnceLock::new();
+    OVERRIDE
+        .get_or_init(|| env_flag("VARTUI_HIDE_NOTES"))
+        .unwrap_or(config.hide_notes)
+}
+
+fn env_flag(name: &str) -> Option<bool> {
+    let value = env::var(name).ok()?;
+    Some(matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "1" | "true" | "yes"
+    ))
+}
+
+// "h/Esc" for the help bar; empty when no key returns to the days panel
+pub fn focus_days_label(keys: &[String]) -> String {
+    keys.iter()
+        .map(|key| match key.trim().to_ascii_lowercase().as_str() {
+            "esc" | "escape" => "Esc".to_string(),
+            "left" => "Left".to_string(),
+            "backspace" => "Backspace".to_string(),
+            _ => key.trim().to_string(),
+        })
+        .filter(|key| !key.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -6,17 +6,28 @@ pub mod tui;
 use chrono::{Datelike, Local};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
 };
+use ratatui_themes::ThemePalette;
 
-use crate::application::app::{App, AppFocus, InputMode};
+use crate::application::app::{App, AppFocus, BillableFilter, InputMode, LoadState, StatusKind};
+use crate::domain::config::WeekendMode;
+use crate::domain::models::{Day, Entry};
+use crate::domain::targets::{day_remaining_hours, is_weekend, target_hours, week_remaining_hours};
+use crate::ui::components::bookmark_modal::render_bookmark_picker;
 use crate::ui::components::config_modal::render_config_modal;
 use crate::ui::components::entry_modal::render_add_entry_modal;
+use crate::ui::components::search_modal::render_search_results;
+use crate::ui::components::template_modal::render_template_picker;
+use crate::ui::helpers::{
+    display_note, duration_bar, focus_days_label, top_right_rect, truncate_with_ellipsis,
+};
 use crate::ui::theme::{palette_with_override, resolve_theme_slug_with_override};
-use crate::utils::parsing::parse_date;
+use crate::utils::i18n::{Text, tr, tr_args, weekday_short};
+use crate::utils::parsing::{format_duration, format_total, parse_date};
 
 pub fn ui(frame: &mut Frame, app: &mut App) {
     let preview_theme = if app.input_mode == InputMode::Configuring {
@@ -38,26 +49,32 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
 
     let top = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .constraints([
+            Constraint::Percentage(app.days_split),
+            Constraint::Percentage(100 - app.days_split),
+        ])
         .split(layout[0]);
 
-    let day_items: Vec<ListItem> = app
-        .days
+    let hhmm = app.config.uses_hhmm();
+    let visible_days = app.visible_day_indices();
+    let today = Local::now().date_naive();
+    let weekend = app.config.weekend_mode;
+    let day_items: Vec<ListItem> = visible_days
         .iter()
+        .filter_map(|&idx| app.days.get(idx))
         .map(|day| {
             let hours = day.total_hours();
-            let date_parsed = parse_date(&day.date).unwrap_or_else(|| Local::now().date_naive());
+            let is_today = parse_date(&day.date) == Some(today);
+            let date_parsed = parse_date(&day.date).unwrap_or(today);
             let weekday = date_parsed.weekday();
 
-            let target = match weekday {
-                chrono::Weekday::Fri => 8.0,
-                chrono::Weekday::Sat | chrono::Weekday::Sun => 0.0,
-                _ => 9.0, // Mon-Thu
-            };
+            let target = target_hours(weekday, weekend);
 
-            let is_future = date_parsed > Local::now().date_naive();
+            let is_future = date_parsed > today;
 
-            let color = if weekday == chrono::Weekday::Sat || weekday == chrono::Weekday::Sun {
+            let color = if is_weekend(weekday) && weekend == WeekendMode::Ignore {
+                palette.muted
+            } else if is_weekend(weekday) && weekend == WeekendMode::Bonus {
                 if hours > 0.0 {
                     palette.success
                 } else {
@@ -65,28 +82,97 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
                 }
             } else if is_future {
                 palette.muted
+            } else if hours > target * app.config.overtime_factor {
+                palette.warning
             } else if hours >= target {
                 palette.success
             } else {
                 palette.error
             };
 
+            let (marker, date_style) = if is_today {
+                (
+                    "● ",
+                    Style::default()
+                        .fg(palette.accent)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default())
+            };
+
+            let date_label = if app.config.show_weekday {
+                format!(
+                    "{} {}  ",
+                    weekday_short(app.config.language, weekday),
+                    day.date
+                )
+            } else {
+                format!("{}  ", day.date)
+            };
+
             ListItem::new(Line::from(vec![
-                Span::styled(format!("{}  ", day.date), Style::default()),
-                Span::styled(format!("{:>4.1}h", hours), Style::default().fg(color)),
+                Span::styled(marker, Style::default().fg(palette.accent)),
+                Span::styled(date_label, date_style),
+                Span::styled(
+                    format!("{:>5}", day_duration(day, app.show_minutes, hhmm)),
+                    Style::default().fg(color),
+                ),
+                Span::styled(
+                    format!(" ({})", day.entries.len()),
+                    Style::default().fg(palette.muted),
+                ),
             ]))
         })
         .collect();
 
+    let lang = app.config.language;
     let range_label = app.date_range.label();
-    let days_title = if app.days.is_empty() {
-        format!("Dias (0/0) {}", range_label)
+    let hidden = app.days.len() - visible_days.len();
+    let hidden_label = if hidden > 0 {
+        tr_args(lang, Text::HiddenDays, &[&hidden.to_string()])
     } else {
+        String::new()
+    };
+    let hidden_label = if app.hidden_zero_entries > 0 {
         format!(
-            "Dias ({}/{}) {}",
-            app.selected_index() + 1,
-            app.days.len(),
-            range_label
+            "{}{}",
+            hidden_label,
+            tr_args(
+                lang,
+                Text::HiddenZeroEntries,
+                &[&app.hidden_zero_entries.to_string()]
+            )
+        )
+    } else {
+        hidden_label
+    };
+    let hidden_label = if app.sort_under_target_first {
+        format!("{} {}", hidden_label, tr(lang, Text::UnderTargetFirst))
+    } else {
+        hidden_label
+    };
+    let hidden_label = if app.week_steps {
+        format!("{} {}", hidden_label, tr(lang, Text::WeekSteps))
+    } else {
+        hidden_label
+    };
+    let selected_position = app.selected_view_position();
+    let days_title = if visible_days.is_empty() {
+        format!(
+            "{} (0/0) {}{}",
+            tr(lang, Text::DaysTitle),
+            range_label,
+            hidden_label
+        )
+    } else {
+        format!(
+            "{} ({}/{}) {}{}",
+            tr(lang, Text::DaysTitle),
+            selected_position.unwrap_or(0) + 1,
+            visible_days.len(),
+            range_label,
+            hidden_label
         )
     };
 
@@ -106,10 +192,38 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         Style::default().fg(palette.muted)
     };
 
+    let mut days_block = Block::default().title(days_title);
+    // The selected day's week up to that day; today when nothing is selected
+    let week_until = app
+        .selected_day()
+        .and_then(|day| parse_date(&day.date))
+        .unwrap_or_else(|| Local::now().date_naive());
+    if let Some(remaining) = week_remaining_hours(&app.days, week_until, weekend) {
+        let footer = if remaining > 0.0 {
+            Span::styled(
+                tr_args(
+                    lang,
+                    Text::WeekRemaining,
+                    &[&format_duration(remaining, hhmm)],
+                ),
+                Style::default().fg(palette.warning),
+            )
+        } else {
+            Span::styled(
+                tr_args(
+                    lang,
+                    Text::WeekTargetMet,
+                    &[&format_duration(-remaining, hhmm)],
+                ),
+                Style::default().fg(palette.success),
+            )
+        };
+        days_block = days_block.title_bottom(Line::from(footer));
+    }
+
     let days_list = List::new(day_items)
         .block(
-            Block::default()
-                .title(days_title)
+            days_block
                 .borders(Borders::ALL)
                 .border_style(days_border)
                 .style(Style::default().bg(palette.bg).fg(palette.fg)),
@@ -122,27 +236,133 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         )
         .highlight_symbol("-> ");
 
-    frame.render_stateful_widget(days_list, top[0], &mut app.day_state);
+    app.day_view_state.select(selected_position);
+    frame.render_stateful_widget(days_list, top[0], &mut app.day_view_state);
+
+    if visible_days.is_empty() {
+        let message = if app.days_load == LoadState::Loading {
+            tr(lang, Text::LoadingView)
+        } else if hidden > 0 {
+            tr(lang, Text::AllDaysHidden)
+        } else {
+            tr(lang, Text::NoDaysInRange)
+        };
+        render_empty_state(frame, top[0], message, &palette);
+    }
 
-    let (detail_title, entries) = match app.selected_day() {
-        Some(day) => (format!("Registros - {}", day.date), day.entries.as_slice()),
-        None => ("Registros".to_string(), &[][..]),
+    let (mut detail_title, entries, entries_date) = match app.selected_day() {
+        Some(day) => (
+            format!("{} - {}", tr(lang, Text::EntriesTitle), day.date),
+            day.entries.as_slice(),
+            day.date.as_str(),
+        ),
+        None => (tr(lang, Text::EntriesTitle).to_string(), &[][..], ""),
     };
+    if let Some(note) = app.day_note(entries_date) {
+        detail_title.push_str(&format!(" — {}", display_note(&app.config, note)));
+    }
+    if app.billable_filter != BillableFilter::All {
+        detail_title.push_str(&format!(
+            " [{}]",
+            app.billable_filter.label(app.config.language)
+        ));
+    }
+    if let Some((_, name)) = &app.project_filter {
+        detail_title.push_str(&format!(
+            " [{}: {} en rango]",
+            name,
+            format_total(app.project_filter_hours(), hhmm, app.config.compact_totals)
+        ));
+    }
+    let visible_entries = app.visible_entry_indices();
 
-    let entry_items: Vec<ListItem> = entries
+    // Project column takes ~30% of the panel (minus borders and highlight symbol)
+    let entries_inner_width = top[1].width.saturating_sub(4) as usize;
+    let project_width = (entries_inner_width * 30 / 100).max(8);
+    let max_entry_hours = entries.iter().map(|entry| entry.hours).fold(0.0, f32::max);
+    let show_bars = app.config.show_duration_bars;
+    let show_project_id = app.config.show_project_id;
+    let entry_items: Vec<ListItem> = visible_entries
         .iter()
+        .filter_map(|&idx| entries.get(idx))
         .map(|entry| {
-            ListItem::new(format!(
-                "{:<14} {:>4.1}h  {}",
-                entry.project, entry.hours, entry.note
-            ))
+            let project = if show_project_id && entry.project_id != 0 {
+                format!("[{}] {}", entry.project_id, entry.project)
+            } else {
+                entry.project.clone()
+            };
+            // Added or changed by the last refresh
+            let fresh = app.is_fresh_entry(entries_date, entry);
+            let mut spans = vec![Span::styled(
+                format!(
+                    "{:<width$} {:>5}  ",
+                    truncate_with_ellipsis(&project, project_width),
+                    entry_duration(entry, app.show_minutes, hhmm),
+                    width = project_width
+                ),
+                if fresh {
+                    Style::default().fg(palette.success)
+                } else {
+                    Style::default()
+                },
+            )];
+            if show_bars {
+                spans.push(Span::styled(
+                    format!("{} ", duration_bar(entry.hours, max_entry_hours, 6)),
+                    Style::default().fg(palette.info),
+                ));
+            }
+            if fresh {
+                spans.push(Span::styled("+ ", Style::default().fg(palette.success)));
+            }
+            spans.push(Span::raw(
+                display_note(&app.config, &entry.note).to_string(),
+            ));
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
+    // Filtered subset against the whole day, so "how long on X today" is one glance
+    let mut entries_block = Block::default().title(detail_title);
+    let mut filter_labels = Vec::new();
+    if app.billable_filter != BillableFilter::All {
+        filter_labels.push(app.billable_filter.label(app.config.language).to_string());
+    }
+    if let Some((_, name)) = &app.project_filter {
+        filter_labels.push(name.clone());
+    }
+    if !filter_labels.is_empty() && !entries.is_empty() {
+        let filtered_minutes: i32 = visible_entries
+            .iter()
+            .filter_map(|&idx| entries.get(idx))
+            .map(|entry| entry.minutes)
+            .sum();
+        let day_minutes: i32 = entries.iter().map(|entry| entry.minutes).sum();
+        let total = |minutes: i32| {
+            if app.show_minutes {
+                format!("{minutes}m")
+            } else {
+                format_duration(minutes as f32 / 60.0, hhmm)
+            }
+        };
+        entries_block = entries_block.title_bottom(Line::from(Span::styled(
+            tr_args(
+                lang,
+                Text::FilteredTotal,
+                &[
+                    &filter_labels.join(", "),
+                    &visible_entries.len().to_string(),
+                    &total(filtered_minutes),
+                    &total(day_minutes),
+                ],
+            ),
+            Style::default().fg(palette.info),
+        )));
+    }
+
     let entries_list = List::new(entry_items)
         .block(
-            Block::default()
-                .title(detail_title)
+            entries_block
                 .borders(Borders::ALL)
                 .border_style(entries_border)
                 .style(Style::default().bg(palette.bg).fg(palette.fg)),
@@ -155,37 +375,144 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         )
         .highlight_symbol("» ");
 
-    frame.render_stateful_widget(entries_list, top[1], &mut app.entry_state);
+    let entries_filtered_out = !entries.is_empty();
+    let entry_position = app.selected_entry_view_position();
+    app.entry_view_state.select(entry_position);
+    frame.render_stateful_widget(entries_list, top[1], &mut app.entry_view_state);
+
+    if visible_entries.is_empty() {
+        let message = if app.days_load == LoadState::Loading {
+            tr(lang, Text::LoadingView)
+        } else if entries_filtered_out {
+            tr(lang, Text::NoEntriesForFilter)
+        } else {
+            tr(lang, Text::NoEntries)
+        };
+        render_empty_state(frame, top[1], message, &palette);
+    }
 
     let (actions_text, prompt_len) = if app.input_mode == InputMode::Editing {
-        let prompt = "Rango (YYYY-MM-DD..YYYY-MM-DD): ";
+        let prompt = tr(lang, Text::RangePrompt);
+        let text = format!(
+            "{}{}  {}  |  {}",
+            prompt,
+            app.input,
+            app.status,
+            tr(lang, Text::RangeHint)
+        );
+        (text, Some(prompt.len()))
+    } else if app.input_mode == InputMode::NamingBookmark {
+        let prompt = tr(lang, Text::BookmarkPrompt);
+        let text = format!(
+            "{}{}  {}  |  {}",
+            prompt,
+            app.input,
+            app.status,
+            tr_args(lang, Text::BookmarkHint, &[&app.date_range.label()])
+        );
+        (text, Some(prompt.len()))
+    } else if app.input_mode == InputMode::EditingDayNote {
+        let date = app
+            .selected_day()
+            .map(|day| day.date.as_str())
+            .unwrap_or("");
+        let prompt = tr_args(lang, Text::DayNotePrompt, &[date]);
         let text = format!(
-            "{}{}  {}  |  Enter: aplicar  Esc: cancelar",
-            prompt, app.input, app.status
+            "{}{}  {}  |  {}",
+            prompt,
+            app.input,
+            app.status,
+            tr(lang, Text::DayNoteHint)
         );
+        (text, Some(prompt.chars().count()))
+    } else if matches!(
+        app.input_mode,
+        InputMode::ConfirmingClearDay | InputMode::ConfirmingUndo | InputMode::AddingMinutes
+    ) {
+        let prompt = "> ";
+        let text = format!(
+            "{}{}  {}  |  {}",
+            prompt,
+            app.input,
+            app.status,
+            tr(lang, Text::CancelHint)
+        );
+        (text, Some(prompt.len()))
+    } else if app.input_mode == InputMode::Searching {
+        let prompt = tr(lang, Text::SearchPrompt);
+        let text = format!("{}{}  |  {}", prompt, app.input, tr(lang, Text::SearchHint));
         (text, Some(prompt.len()))
     } else {
         let actions = if app.focus == AppFocus::Entries {
-            format!(
-                "j/k: mover | h: volver | d: duplicar | q: salir |  {}",
-                app.status
-            )
+            let keys = focus_days_label(&app.config.focus_days_keys);
+            let back = if keys.is_empty() {
+                String::new()
+            } else {
+                tr_args(lang, Text::BackHelp, &[&keys])
+            };
+            tr_args(lang, Text::EntriesHelp, &[&back])
         } else {
-            format!(
-                "j/k: mover | l: entries | f: rango | r: refrescar | n: nuevo | c: config | q: salir |  {}",
-                app.status
-            )
+            tr(lang, Text::DaysHelp).to_string()
         };
+        // Live progress for the selected workday, updated as entries are created
+        let remaining_today = app
+            .selected_day()
+            .and_then(|day| day_remaining_hours(day, weekend))
+            .map(|remaining| {
+                tr_args(
+                    lang,
+                    Text::RemainingToday,
+                    &[&format_duration(remaining, hhmm)],
+                )
+            })
+            .unwrap_or_default();
+        let actions = format!("{}{}{}", actions, remaining_today, app.status);
         (actions, None)
     };
 
-    let actions_block = Block::default()
-        .title(format!(
-            "Acciones [{}]",
+    let mut actions_spans = vec![
+        Span::raw(format!(
+            "{} [{}] ",
+            tr(lang, Text::ActionsTitle),
             resolve_theme_slug_with_override(&app.config, preview_theme)
-        ))
+        )),
+        Span::styled(
+            format!("[{}] ", range_label),
+            Style::default()
+                .fg(palette.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        load_indicator("D", &app.days_load, &palette),
+        load_indicator("P", &app.projects_load, &palette),
+    ];
+    if let Some(secs) = app.rate_limit_remaining_secs() {
+        actions_spans.push(Span::styled(
+            format!("[429 {secs}s] "),
+            Style::default()
+                .fg(palette.warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let actions_title = Line::from(actions_spans);
+
+    let actions_border = match app.input_mode {
+        InputMode::Normal => palette.accent,
+        InputMode::Editing
+        | InputMode::NamingBookmark
+        | InputMode::AddingMinutes
+        | InputMode::EditingDayNote => palette.warning,
+        InputMode::ConfirmingClearDay | InputMode::ConfirmingUndo => palette.error,
+        InputMode::AddingEntry => palette.success,
+        InputMode::Configuring
+        | InputMode::PickingBookmark
+        | InputMode::PickingTemplate
+        | InputMode::Searching => palette.info,
+    };
+
+    let actions_block = Block::default()
+        .title(actions_title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(palette.accent))
+        .border_style(Style::default().fg(actions_border))
         .style(Style::default().bg(palette.bg).fg(palette.fg));
     let actions_area = actions_block.inner(layout[1]);
     let actions = Paragraph::new(actions_text)
@@ -208,4 +535,80 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
     if app.input_mode == InputMode::Configuring {
         render_config_modal(frame, app);
     }
+
+    if app.input_mode == InputMode::PickingBookmark {
+        render_bookmark_picker(frame, app);
+    }
+
+    if app.input_mode == InputMode::PickingTemplate {
+        render_template_picker(frame, app);
+    }
+
+    if app.input_mode == InputMode::Searching {
+        render_search_results(frame, app);
+    }
+
+    if let Some((message, kind, _)) = &app.toast {
+        let color = match kind {
+            StatusKind::Info => palette.success,
+            StatusKind::Error => palette.error,
+        };
+        let width = (message.chars().count() as u16 + 4).min(frame.area().width / 2);
+        let area = top_right_rect(width, 3, frame.area());
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(message.as_str()).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(color))
+                    .style(Style::default().bg(palette.bg).fg(palette.fg)),
+            ),
+            area,
+        );
+    }
+}
+
+fn load_indicator(label: &str, state: &LoadState, palette: &ThemePalette) -> Span<'static> {
+    let color = match state {
+        LoadState::Idle => palette.muted,
+        LoadState::Loading => palette.warning,
+        LoadState::Loaded => palette.success,
+        LoadState::Failed(_) => palette.error,
+    };
+    Span::styled(format!("[{}]", label), Style::default().fg(color))
+}
+
+// Centered muted hint drawn inside a bordered panel that has nothing to list
+fn render_empty_state(frame: &mut Frame, area: Rect, message: &str, palette: &ThemePalette) {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    if inner.height == 0 {
+        return;
+    }
+    let line_area = Rect {
+        y: inner.y + inner.height.saturating_sub(1) / 2,
+        height: 1,
+        ..inner
+    };
+    frame.render_widget(
+        Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(palette.muted)),
+        line_area,
+    );
+}
+
+fn day_duration(day: &Day, show_minutes: bool, hhmm: bool) -> String {
+    if show_minutes {
+        format!("{}m", day.total_minutes())
+    } else {
+        format_duration(day.total_hours(), hhmm)
+    }
+}
+
+fn entry_duration(entry: &Entry, show_minutes: bool, hhmm: bool) -> String {
+    if show_minutes {
+        format!("{}m", entry.minutes)
+    } else {
+        format_duration(entry.hours, hhmm)
+    }
 }
@@ -2,8 +2,11 @@ use std::str::FromStr;
 use std::sync::OnceLock;
 
 #[cfg(target_os = "macos")]
-use std::process::Command;
+use std::process::{Command, Stdio};
+#[cfg(target_os = "macos")]
+use std::time::{Duration, Instant};
 
+use ratatui::style::Color;
 use ratatui_themes::{ThemeName, ThemePalette};
 
 use crate::domain::config::AppConfig;
@@ -24,15 +27,22 @@ pub const THEME_CATALOG: &[&str] = &[
     "kanagawa",
     "everforest",
     "cyberpunk",
+    HIGH_CONTRAST,
 ];
 
+// Not part of ratatui-themes; its palette is built by hand below
+pub const HIGH_CONTRAST: &str = "high-contrast";
+
 pub fn palette_from_config(config: &AppConfig) -> ThemePalette {
-    resolve_theme_name(&config.theme).palette()
+    palette_with_override(config, None)
 }
 
 pub fn palette_with_override(config: &AppConfig, override_theme: Option<&str>) -> ThemePalette {
     let key = override_theme.unwrap_or(&config.theme);
-    resolve_theme_name(key).palette()
+    if is_high_contrast(key) {
+        return high_contrast_palette();
+    }
+    resolve_config_theme(config, key).palette()
 }
 
 pub fn resolve_theme_slug_with_override(
@@ -40,17 +50,64 @@ pub fn resolve_theme_slug_with_override(
     override_theme: Option<&str>,
 ) -> &'static str {
     let key = override_theme.unwrap_or(&config.theme);
-    resolve_theme_name(key).slug()
+    if is_high_contrast(key) {
+        return HIGH_CONTRAST;
+    }
+    resolve_config_theme(config, key).slug()
+}
+
+fn is_high_contrast(raw: &str) -> bool {
+    normalize_theme_key(raw) == HIGH_CONTRAST
+}
+
+// Pure black/white with saturated accents, for low-vision users and bad projectors
+fn high_contrast_palette() -> ThemePalette {
+    ThemePalette {
+        accent: Color::Yellow,
+        secondary: Color::LightCyan,
+        bg: Color::Black,
+        fg: Color::White,
+        muted: Color::Gray,
+        selection: Color::Blue,
+        error: Color::LightRed,
+        warning: Color::LightYellow,
+        success: Color::LightGreen,
+        info: Color::LightCyan,
+    }
 }
 
-pub fn resolve_theme_name(raw: &str) -> ThemeName {
+// Catalog slug (or "auto") for a config value; None when it would fall back silently
+pub fn canonical_theme_key(raw: &str) -> Option<String> {
+    let value = normalize_theme_key(raw);
+    (value == "auto" || THEME_CATALOG.contains(&value.as_str())).then_some(value)
+}
+
+pub fn resolve_config_theme(config: &AppConfig, raw: &str) -> ThemeName {
+    resolve_theme_name(raw, config.system_theme.as_deref())
+}
+
+// For "auto": VARTUI_SYSTEM_THEME, then the config hint, then OS detection
+pub fn resolve_theme_name(raw: &str, hint: Option<&str>) -> ThemeName {
     let value = normalize_theme_key(raw);
     if value == "auto" {
-        return detect_system_theme_name();
+        let forced = parse_theme_hint(std::env::var("VARTUI_SYSTEM_THEME").ok().as_deref())
+            .or_else(|| parse_theme_hint(hint));
+        return match forced {
+            Some(is_dark) => theme_for_mode(is_dark),
+            None => detect_system_theme_name(),
+        };
     }
     ThemeName::from_str(&value).unwrap_or(ThemeName::TokyoNight)
 }
 
+fn theme_for_mode(is_dark: bool) -> ThemeName {
+    if is_dark {
+        ThemeName::TokyoNight
+    } else {
+        ThemeName::CatppuccinLatte
+    }
+}
+
 fn normalize_theme_key(raw: &str) -> String {
     let value = raw.trim().to_ascii_lowercase();
     if value.is_empty() {
@@ -64,6 +121,7 @@ fn normalize_theme_key(raw: &str) -> String {
         "catppuccin-light" | "latte" => "catppuccin-latte".to_string(),
         "gruvbox" => "gruvbox-dark".to_string(),
         "solarized" => "solarized-dark".to_string(),
+        "contrast" | "hc" | "high-contrast-dark" => HIGH_CONTRAST.to_string(),
         other => other.to_string(),
     }
 }
@@ -74,39 +132,42 @@ fn detect_system_theme_name() -> ThemeName {
 }
 
 fn detect_system_theme_uncached() -> ThemeName {
-    if let Some(is_dark) = parse_theme_hint(std::env::var("VARTUI_SYSTEM_THEME").ok().as_deref()) {
-        return if is_dark {
-            ThemeName::TokyoNight
-        } else {
-            ThemeName::CatppuccinLatte
-        };
-    }
-
     #[cfg(target_os = "macos")]
     {
         if let Some(is_dark) = detect_macos_dark_mode() {
-            return if is_dark {
-                ThemeName::TokyoNight
-            } else {
-                ThemeName::CatppuccinLatte
-            };
+            return theme_for_mode(is_dark);
         }
     }
 
     ThemeName::TokyoNight
 }
 
+// `defaults` can be blocked or slow on managed machines; give up after a short wait
 #[cfg(target_os = "macos")]
 fn detect_macos_dark_mode() -> Option<bool> {
-    let output = Command::new("defaults")
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    let mut child = Command::new("defaults")
         .args(["read", "-g", "AppleInterfaceStyle"])
-        .output()
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
         .ok()?;
 
-    if output.status.success() {
-        Some(true)
-    } else {
-        Some(false)
+    let started = Instant::now();
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status.success()),
+            Ok(None) if started.elapsed() < TIMEOUT => {
+                std::thread::sleep(Duration::from_millis(20))
+            }
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
     }
 }
 
@@ -125,12 +186,59 @@ mod tests {
 
     #[test]
     fn resolves_aliases() {
-        assert_eq!(resolve_theme_name("catppuccin").slug(), "catppuccin-mocha");
-        assert_eq!(resolve_theme_name("tokyo").slug(), "tokyo-night");
+        assert_eq!(
+            resolve_theme_name("catppuccin", None).slug(),
+            "catppuccin-mocha"
+        );
+        assert_eq!(resolve_theme_name("tokyo", None).slug(), "tokyo-night");
+    }
+
+    #[test]
+    fn auto_theme_uses_config_hint() {
+        if std::env::var("VARTUI_SYSTEM_THEME").is_ok() {
+            return;
+        }
+        assert_eq!(
+            resolve_theme_name("auto", Some("light")).slug(),
+            "catppuccin-latte"
+        );
+        assert_eq!(
+            resolve_theme_name("auto", Some("dark")).slug(),
+            "tokyo-night"
+        );
+    }
+
+    #[test]
+    fn canonical_theme_key_rejects_unknown_themes() {
+        assert_eq!(
+            canonical_theme_key(" Mocha ").as_deref(),
+            Some("catppuccin-mocha")
+        );
+        assert_eq!(canonical_theme_key("system").as_deref(), Some("auto"));
+        assert_eq!(canonical_theme_key("unknown-theme"), None);
+    }
+
+    #[test]
+    fn high_contrast_theme_is_selectable() {
+        assert_eq!(canonical_theme_key("HC").as_deref(), Some(HIGH_CONTRAST));
+        let config = AppConfig {
+            theme: "high-contrast".to_string(),
+            ..AppConfig::default()
+        };
+        let palette = palette_from_config(&config);
+        assert_eq!(palette.bg, Color::Black);
+        assert_eq!(palette.fg, Color::White);
+        assert_eq!(
+            resolve_theme_slug_with_override(&config, None),
+            HIGH_CONTRAST
+        );
     }
 
     #[test]
     fn falls_back_to_default_theme() {
-        assert_eq!(resolve_theme_name("unknown-theme").slug(), "tokyo-night");
+        assert_eq!(
+            resolve_theme_name("unknown-theme", None).slug(),
+            "tokyo-night"
+        );
     }
 }
@@ -2,19 +2,84 @@ use crossterm::{
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use ratatui::{Terminal, backend::CrosstermBackend};
-use std::io;
+use ratatui::{
+    Terminal,
+    backend::{CrosstermBackend, TestBackend},
+};
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::application::app::App;
+use crate::ui::ui;
+
+// Set once raw mode and the alternate screen are both on; restore only undoes a full setup
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
 
+// Fails with a readable error instead of leaving the terminal half-configured
 pub fn setup_terminal() -> io::Result<Terminal<CrosstermBackend<io::Stdout>>> {
-    enable_raw_mode()?;
+    if !io::stdout().is_terminal() {
+        return Err(setup_error(
+            "la salida no es una terminal interactiva; usa `vartui render` o `vartui api`",
+            None,
+        ));
+    }
+    enable_raw_mode()
+        .map_err(|error| setup_error("no se pudo activar el modo raw", Some(error)))?;
+
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
-    Terminal::new(backend)
+    if let Err(error) = execute!(stdout, EnterAlternateScreen) {
+        let _ = disable_raw_mode();
+        return Err(setup_error(
+            "la terminal no soporta la pantalla alternativa",
+            Some(error),
+        ));
+    }
+
+    match Terminal::new(CrosstermBackend::new(stdout)) {
+        Ok(terminal) => {
+            TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
+            Ok(terminal)
+        }
+        Err(error) => {
+            let _ = execute!(io::stdout(), LeaveAlternateScreen);
+            let _ = disable_raw_mode();
+            Err(setup_error("no se pudo iniciar la terminal", Some(error)))
+        }
+    }
 }
 
+// Safe to call more than once: later calls are no-ops. Every step runs even
+// if an earlier one fails; the first error is returned.
 pub fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()
+    if !TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+        return Ok(());
+    }
+    let raw = disable_raw_mode();
+    let screen = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let cursor = terminal.show_cursor();
+    raw.and(screen).and(cursor)
+}
+
+fn setup_error(message: &str, cause: Option<io::Error>) -> io::Error {
+    match cause {
+        Some(cause) => io::Error::new(cause.kind(), format!("{message}: {cause}")),
+        None => io::Error::other(message.to_string()),
+    }
+}
+
+// Draws one frame off-screen and returns it as plain text, one line per row
+pub fn render_to_text(app: &mut App, width: u16, height: u16) -> String {
+    let Ok(mut terminal) = Terminal::new(TestBackend::new(width, height));
+    let Ok(_) = terminal.draw(|frame| ui(frame, app));
+
+    let buffer = terminal.backend().buffer();
+    let mut lines = Vec::with_capacity(height as usize);
+    for y in 0..buffer.area.height {
+        let mut line = String::new();
+        for x in 0..buffer.area.width {
+            line.push_str(buffer[(x, y)].symbol());
+        }
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n")
 }
@@ -0,0 +1,642 @@
+use chrono::Weekday;
+
+use crate::domain::config::Language;
+
+// User-facing strings; `{}` placeholders are filled in order by `tr_args`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Text {
+    // Main view
+    DaysTitle,
+    HiddenDays,
+    HiddenZeroEntries,
+    UnderTargetFirst,
+    WeekSteps,
+    WeekRemaining,
+    WeekTargetMet,
+    EntriesTitle,
+    ActionsTitle,
+    LoadingView,
+    AllDaysHidden,
+    NoDaysInRange,
+    NoEntriesForFilter,
+    NoEntries,
+    RangePrompt,
+    RangeHint,
+    BookmarkPrompt,
+    BookmarkHint,
+    DayNotePrompt,
+    DayNoteHint,
+    SearchPrompt,
+    SearchHint,
+    DaysHelp,
+    EntriesHelp,
+    BackHelp,
+    RemainingToday,
+    DaySummary,
+    FilteredTotal,
+    RangeSummary,
+    // Statuses
+    StatusLoading,
+    StatusReady,
+    StatusReadyProjectsError,
+    StatusNoToken,
+    StatusNoTokenPrompt,
+    StatusNoTokenHeadless,
+    StatusConfigSaveError,
+    StatusConfigReset,
+    StatusDefaultFromView,
+    StatusConfigResetError,
+    StatusRefreshing,
+    StatusUpdated,
+    StatusChangedDays,
+    StatusRateLimited,
+    StatusRateLimitCleared,
+    StatusProjectsLoaded,
+    StatusProjectsError,
+    StatusCreating,
+    StatusEntryCreated,
+    StatusEntryCreatedAddAnother,
+    StatusCreateError,
+    StatusEntryUpdated,
+    StatusUpdateError,
+    StatusEmptyFields,
+    StatusInvalidTime,
+    StatusShortEntry,
+    StatusDayEmpty,
+    StatusDayMissingIds,
+    StatusClearDayPrompt,
+    StatusClearDayMismatch,
+    StatusClearDayError,
+    StatusClearDayDone,
+    StatusClearDayPartial,
+    StatusClearDayCancelled,
+    StatusProjectsPending,
+    StatusSplitLimit,
+    StatusSplit,
+    StatusSplitNotSaved,
+    AddMinutesNewTarget,
+    StatusAddMinutesPrompt,
+    StatusAddMinutesError,
+    StatusMinutesLogged,
+    StatusMinutesAdded,
+    StatusCancelled,
+    StatusCopyError,
+    StatusCommandCopied,
+    StatusDayJsonCopied,
+    StatusRangeCopied,
+    StatusSummaryCopied,
+    StatusNoTemplates,
+    StatusTemplateError,
+    StatusTemplateNotFound,
+    StatusTemplateApplied,
+    StatusTraceOn,
+    StatusTraceOff,
+    StatusNothingToUndo,
+    StatusUndoPrompt,
+    StatusUndone,
+    StatusUndoError,
+    StatusUndoCancelled,
+    StatusExactMinutes,
+    StatusHourDurations,
+    StatusWeekSteps,
+    StatusDaySteps,
+    StatusDayNoteCleared,
+    StatusDayNoteSaved,
+    StatusDayNoteError,
+    StatusNothingToRetry,
+    StatusEntryUpdatedAddAnother,
+    BillableAll,
+    BillableOnly,
+    BillableNon,
+    StatusSortUnderTarget,
+    StatusSortByDate,
+    StatusEmptyDaysHidden,
+    StatusShowingAllDays,
+    StatusTheme,
+    StatusThemeSaveError,
+    StatusBillableFilter,
+    StatusProjectFilterCleared,
+    StatusProjectFilter,
+    StatusEntryMissingId,
+    StatusEditingEntry,
+    StatusDayOutOfRange,
+    StatusNoEntryAtIndex,
+    StatusExported,
+    StatusExportError,
+    StatusNoDaySelected,
+    StatusNoEntrySelected,
+    StatusProjectIdNotFound,
+    StatusNoTokenShort,
+    StatusNoEntryIds,
+    StatusSkipped,
+    StatusConfigReloaded,
+    StatusRefreshingProjects,
+    StatusRangeError,
+    StatusNoResults,
+    StatusInvalidDate,
+    StatusBookmarkError,
+    StatusEmptyName,
+    StatusBookmarkSaved,
+    StatusNoBookmarks,
+    StatusBookmarkNotFound,
+    StatusBookmarkInvalidRange,
+    StatusLoadInterrupted,
+    StatusFormComplete,
+    StatusNoProjectWithId,
+    ProjectOfClient,
+    StatusAmbiguousProject,
+    StatusProjectSelected,
+    StatusClientError,
+    StatusConfiguring,
+    StatusConfigSavedUnchanged,
+    StatusConfigSaved,
+    ChangeToken,
+    ChangeBaseUrl,
+    ChangeRange,
+    ChangeRangeRemoved,
+    ChangeTheme,
+    IssueEmptyDate,
+    IssueNoProject,
+    IssueEmptyDescription,
+    IssueInvalidMinutes,
+    StatusNoTokenPressC,
+    CancelHint,
+    EditEntryTitle,
+    NewEntryTitle,
+    DateFieldTitle,
+    ProjectFieldSelected,
+    ProjectFieldLoading,
+    ProjectFieldById,
+    ProjectFieldSearch,
+    DescriptionFieldTitle,
+    DurationFieldFocused,
+    DurationFieldTitle,
+    BillableCheckbox,
+    OptionsTitle,
+    DuplicateProjectName,
+    SubmitEnterHint,
+    SubmitCtrlSHint,
+    RetryHint,
+    EntryFormHelp,
+    PayloadPreviewTitle,
+    ConfigTitle,
+    DefaultRangeCustomTitle,
+    DefaultRangeListTitle,
+    ThemeFieldTitle,
+    ConfigHelp,
+    RangesTitle,
+    ThemesTitle,
+    BookmarksTitle,
+    TemplatesTitle,
+    TemplateNonBillable,
+    SearchTitle,
+    SearchResultsTitle,
+    // CLI/MCP help
+    HelpUsage,
+    HelpApiSubcommands,
+    HelpMcpSubcommands,
+    McpHelp,
+    McpRecordHelp,
+    McpInstructions,
+}
+
+pub fn tr(language: Language, text: Text) -> &'static str {
+    let (es, en) = match text {
+        Text::DaysTitle => ("Dias", "Days"),
+        Text::HiddenDays => (" ({} ocultos)", " ({} hidden)"),
+        Text::HiddenZeroEntries => (" ({} reg. de 0 min ocultos)", " ({} 0-min entries hidden)"),
+        Text::UnderTargetFirst => ("[bajo meta primero]", "[under target first]"),
+        Text::WeekSteps => ("[por semana]", "[by week]"),
+        Text::WeekRemaining => (" faltan {} esta semana ", " {} left this week "),
+        Text::WeekTargetMet => (" meta semanal cumplida (+{}) ", " weekly target met (+{}) "),
+        Text::EntriesTitle => ("Registros", "Entries"),
+        Text::ActionsTitle => ("Acciones", "Actions"),
+        Text::LoadingView => ("Cargando...", "Loading..."),
+        Text::AllDaysHidden => (
+            "Todos los dias estan ocultos. Pulsa 'z' para mostrarlos.",
+            "All days are hidden. Press 'z' to show them.",
+        ),
+        Text::NoDaysInRange => (
+            "Sin dias en el rango. Pulsa 'f' para cambiarlo.",
+            "No days in range. Press 'f' to change it.",
+        ),
+        Text::NoEntriesForFilter => (
+            "Sin registros para este filtro. Pulsa 'x' para cambiarlo.",
+            "No entries for this filter. Press 'x' to change it.",
+        ),
+        Text::NoEntries => (
+            "Sin registros. Pulsa 'n' para agregar.",
+            "No entries. Press 'n' to add one.",
+        ),
+        Text::RangePrompt => (
+            "Rango (YYYY-MM-DD..YYYY-MM-DD): ",
+            "Range (YYYY-MM-DD..YYYY-MM-DD): ",
+        ),
+        Text::RangeHint => ("Enter: aplicar  Esc: cancelar", "Enter: apply  Esc: cancel"),
+        Text::BookmarkPrompt => ("Nombre del marcador: ", "Bookmark name: "),
+        Text::BookmarkHint => (
+            "Enter: guardar {}  Esc: cancelar",
+            "Enter: save {}  Esc: cancel",
+        ),
+        Text::DayNotePrompt => ("Nota del dia {}: ", "Note for {}: "),
+        Text::DayNoteHint => (
+            "Enter: guardar (vacia la borra)  Esc: cancelar",
+            "Enter: save (empty removes it)  Esc: cancel",
+        ),
+        Text::SearchPrompt => ("Buscar: ", "Search: "),
+        Text::SearchHint => (
+            "Up/Down: mover  Enter: ir al dia  Esc: cancelar",
+            "Up/Down: move  Enter: go to day  Esc: cancel",
+        ),
+        Text::DaysHelp => (
+            "j/k: mover | l: entries | /: buscar | y: copiar dia | Y: copiar rango | s/S: resumen dia/rango | f: rango | b/B: marcadores | T: plantillas | r: refrescar | P: proyectos | z: ocultar vacios | O: bajo meta primero | w: j/k por semana | </>: ancho | Ctrl+Izq/Der: tema | D: borrar dia | N: nota del dia | Ctrl+Z: deshacer | E: exportar ics | n: nuevo | c: config | q: salir |  ",
+            "j/k: move | l: entries | /: search | y: copy day | Y: copy range | s/S: day/range summary | f: range | b/B: bookmarks | T: templates | r: refresh | P: projects | z: hide empty | O: under target first | w: j/k by week | </>: width | Ctrl+Left/Right: theme | D: clear day | N: day note | Ctrl+Z: undo | E: export ics | n: new | c: config | q: quit |  ",
+        ),
+        Text::EntriesHelp => (
+            "j/k: mover | {}e: editar | +: sumar minutos | d: duplicar | y: copiar cmd | x: facturables | p: solo proyecto | m: minutos | q: salir |  ",
+            "j/k: move | {}e: edit | +: add minutes | d: duplicate | y: copy cmd | x: billable | p: only project | m: minutes | q: quit |  ",
+        ),
+        Text::BackHelp => ("{}: volver | ", "{}: back | "),
+        Text::RemainingToday => ("restante hoy: {} |  ", "left today: {} |  "),
+        Text::DaySummary => ("{}: {} en {} registros", "{}: {} across {} entries"),
+        Text::FilteredTotal => (
+            " filtro: {} — {} registros, {} de {} ",
+            " filter: {} — {} entries, {} of {} ",
+        ),
+        Text::RangeSummary => (
+            "{}: {} en {} registros ({} dias con horas)",
+            "{}: {} across {} entries ({} days with time)",
+        ),
+        Text::StatusLoading => (
+            "cargando proyectos y dias...",
+            "loading projects and days...",
+        ),
+        Text::StatusReady => (
+            "listo: {} dias, {} proyectos",
+            "ready: {} days, {} projects",
+        ),
+        Text::StatusReadyProjectsError => (
+            "listo: {} dias; error proyectos: {}",
+            "ready: {} days; error loading projects: {}",
+        ),
+        Text::StatusNoToken => (
+            "No hay token configurado. Completa la configuracion para empezar.",
+            "No token configured. Complete the configuration to start.",
+        ),
+        Text::StatusNoTokenPrompt => (
+            "No hay token configurado. Ingresa VAR Token y presiona Enter.",
+            "No token configured. Enter the VAR Token and press Enter.",
+        ),
+        Text::StatusNoTokenHeadless => (
+            "No hay token configurado. Define VAR_TOKEN, guarda var_token en la configuracion o agrega token= en ~/.vartui/credentials.",
+            "No token configured. Set VAR_TOKEN, save var_token in the configuration or add token= to ~/.vartui/credentials.",
+        ),
+        Text::StatusConfigSaveError => ("Error guardando: {}", "Error saving: {}"),
+        Text::StatusDefaultFromView => (
+            "rango default <- vista actual ({}); Enter para guardar",
+            "default range <- current view ({}); Enter to save",
+        ),
+        Text::StatusConfigReset => ("Configuracion restablecida", "Configuration reset"),
+        Text::StatusConfigResetError => ("Error restableciendo: {}", "Error resetting: {}"),
+        Text::StatusRefreshing => ("actualizando...", "refreshing..."),
+        Text::StatusUpdated => ("actualizado: {} dias", "updated: {} days"),
+        Text::StatusChangedDays => ("{} ({} con cambios)", "{} ({} changed)"),
+        Text::StatusRateLimited => (
+            "limite alcanzado, reintentando en {}s",
+            "rate limited, retrying in {}s",
+        ),
+        Text::StatusRateLimitCleared => ("limite de solicitudes liberado", "rate limit cleared"),
+        Text::StatusProjectsLoaded => ("proyectos cargados: {}", "projects loaded: {}"),
+        Text::StatusProjectsError => ("error proyectos: {}", "error loading projects: {}"),
+        Text::StatusCreating => ("creando registro...", "creating entry..."),
+        Text::StatusEntryCreated => ("registro creado!", "entry created!"),
+        Text::StatusEntryCreatedAddAnother => {
+            ("registro creado! agrega otro", "entry created! add another")
+        }
+        Text::StatusCreateError => ("error crear: {}", "error creating entry: {}"),
+        Text::StatusEntryUpdated => ("registro #{} actualizado!", "entry #{} updated!"),
+        Text::StatusUpdateError => ("error actualizar: {}", "error updating entry: {}"),
+        Text::StatusEmptyFields => (
+            "error: campos vacios o proyecto invalido",
+            "error: empty fields or invalid project",
+        ),
+        Text::StatusInvalidTime => (
+            "error: tiempo invalido (0 o formato incorrecto)",
+            "error: invalid time (0 or wrong format)",
+        ),
+        Text::StatusShortEntry => (
+            "solo {} min (menos de {}); envia otra vez para confirmar",
+            "only {} min (under {}); submit again to confirm",
+        ),
+        Text::StatusDayEmpty => ("el dia no tiene registros", "the day has no entries"),
+        Text::StatusDayMissingIds => (
+            "el API no envio ids de registros; no se puede borrar",
+            "the API sent no entry ids; cannot delete",
+        ),
+        Text::StatusClearDayPrompt => (
+            "Borrar {} registros de {}? escribe {} y Enter para confirmar",
+            "Delete {} entries from {}? type {} and Enter to confirm",
+        ),
+        Text::StatusClearDayMismatch => (
+            "confirmacion incorrecta, no se borro nada",
+            "wrong confirmation, nothing deleted",
+        ),
+        Text::StatusClearDayError => ("error borrar: {}", "error deleting: {}"),
+        Text::StatusClearDayDone => ("borrados {} registros de {}", "deleted {} entries from {}"),
+        Text::StatusClearDayPartial => (
+            "borrados {}/{} registros de {}; fallaron {} ({})",
+            "deleted {}/{} entries from {}; {} failed ({})",
+        ),
+        Text::StatusClearDayCancelled => ("borrado cancelado", "delete cancelled"),
+        Text::StatusProjectsPending => (
+            "cargando proyectos, espera...",
+            "loading projects, please wait...",
+        ),
+        Text::StatusSplitLimit => (
+            "panel de dias al limite ({}%)",
+            "days panel at its limit ({}%)",
+        ),
+        Text::StatusSplit => ("panel de dias: {}%", "days panel: {}%"),
+        Text::StatusSplitNotSaved => (
+            "panel de dias: {}% (no se guardo: {})",
+            "days panel: {}% (not saved: {})",
+        ),
+        Text::AddMinutesNewTarget => ("nuevo registro de {}", "new entry for {}"),
+        Text::StatusAddMinutesPrompt => (
+            "minutos a agregar a {}; Enter para confirmar",
+            "minutes to add to {}; Enter to confirm",
+        ),
+        Text::StatusAddMinutesError => ("error agregar minutos: {}", "error adding minutes: {}"),
+        Text::StatusMinutesLogged => ("+{} min registrados en {}", "+{} min logged on {}"),
+        Text::StatusMinutesAdded => (
+            "+{} min: registro #{} ahora tiene {} min",
+            "+{} min: entry #{} now has {} min",
+        ),
+        Text::StatusCancelled => ("Cancelado", "Cancelled"),
+        Text::StatusCopyError => ("error copiando: {}", "copy error: {}"),
+        Text::StatusCommandCopied => (
+            "comando copiado al portapapeles",
+            "command copied to clipboard",
+        ),
+        Text::StatusDayJsonCopied => ("dia copiado como JSON", "day copied as JSON"),
+        Text::StatusRangeCopied => ("rango copiado: {}", "range copied: {}"),
+        Text::StatusSummaryCopied => ("resumen copiado: {}", "summary copied: {}"),
+        Text::StatusNoTemplates => (
+            "no hay plantillas (entry_templates en la config)",
+            "no templates (entry_templates in the config)",
+        ),
+        Text::StatusTemplateError => ("error plantilla: {}", "template error: {}"),
+        Text::StatusTemplateNotFound => ("plantilla no encontrada: {}", "template not found: {}"),
+        Text::StatusTemplateApplied => ("plantilla: {}", "template: {}"),
+        Text::StatusTraceOn => (
+            "traza HTTP activada (debug.log)",
+            "HTTP trace enabled (debug.log)",
+        ),
+        Text::StatusTraceOff => ("traza HTTP desactivada", "HTTP trace disabled"),
+        Text::StatusNothingToUndo => ("nada que deshacer", "nothing to undo"),
+        Text::StatusUndoPrompt => (
+            "Deshacer registro #{}: {}? Enter: borrar",
+            "Undo entry #{}: {}? Enter: delete",
+        ),
+        Text::StatusUndone => ("registro #{} deshecho", "entry #{} undone"),
+        Text::StatusUndoError => ("error deshacer: {}", "undo error: {}"),
+        Text::StatusUndoCancelled => ("deshacer cancelado", "undo cancelled"),
+        Text::StatusExactMinutes => (
+            "duraciones en minutos exactos",
+            "durations in exact minutes",
+        ),
+        Text::StatusHourDurations => ("duraciones en horas", "durations in hours"),
+        Text::StatusWeekSteps => ("j/k: saltar por semana", "j/k: jump by week"),
+        Text::StatusDaySteps => ("j/k: dia a dia", "j/k: day by day"),
+        Text::StatusDayNoteCleared => ("nota de {} borrada", "note for {} cleared"),
+        Text::StatusDayNoteSaved => ("nota de {} guardada", "note for {} saved"),
+        Text::StatusDayNoteError => ("error nota: {}", "note error: {}"),
+        Text::StatusNothingToRetry => ("nada que reintentar", "nothing to retry"),
+        Text::StatusEntryUpdatedAddAnother => (
+            "registro #{} actualizado! agrega otro",
+            "entry #{} updated! add another",
+        ),
+        Text::BillableAll => ("todos", "all"),
+        Text::BillableOnly => ("facturables", "billable"),
+        Text::BillableNon => ("no facturables", "non-billable"),
+        Text::StatusSortUnderTarget => ("dias bajo meta primero", "days under target first"),
+        Text::StatusSortByDate => ("dias por fecha", "days by date"),
+        Text::StatusEmptyDaysHidden => {
+            ("dias sin horas ocultos: {}", "days without time hidden: {}")
+        }
+        Text::StatusShowingAllDays => ("mostrando todos los dias", "showing all days"),
+        Text::StatusTheme => ("tema: {} ({}/{})", "theme: {} ({}/{})"),
+        Text::StatusThemeSaveError => ("tema {}: no se guardo: {}", "theme {}: not saved: {}"),
+        Text::StatusBillableFilter => ("registros: {}", "entries: {}"),
+        Text::StatusProjectFilterCleared => {
+            ("filtro de proyecto quitado", "project filter cleared")
+        }
+        Text::StatusProjectFilter => ("solo proyecto: {}", "only project: {}"),
+        Text::StatusEntryMissingId => (
+            "el registro no tiene id; el API no lo envio",
+            "the entry has no id; the API did not send it",
+        ),
+        Text::StatusEditingEntry => ("editando registro #{}", "editing entry #{}"),
+        Text::StatusDayOutOfRange => (
+            "dia fuera del rango cargado: {}",
+            "day outside the loaded range: {}",
+        ),
+        Text::StatusNoEntryAtIndex => ("{} no tiene registro {}", "{} has no entry {}"),
+        Text::StatusExported => ("exportado a {}", "exported to {}"),
+        Text::StatusExportError => ("error exportar: {}", "export error: {}"),
+        Text::StatusNoDaySelected => ("no hay dia seleccionado", "no day selected"),
+        Text::StatusNoEntrySelected => ("no hay registro seleccionado", "no entry selected"),
+        Text::StatusProjectIdNotFound => {
+            ("no se encontro el id de {}", "could not find the id of {}")
+        }
+        Text::StatusNoTokenShort => ("no hay token configurado", "no token configured"),
+        Text::StatusNoEntryIds => (
+            "el API no envio ids de registros",
+            "the API sent no entry ids",
+        ),
+        Text::StatusSkipped => ("{}: omitido", "{}: skipped"),
+        Text::StatusConfigReloaded => ("config recargada: {}", "config reloaded: {}"),
+        Text::StatusRefreshingProjects => ("actualizando proyectos...", "refreshing projects..."),
+        Text::StatusRangeError => ("estado: {}", "status: {}"),
+        Text::StatusNoResults => ("sin resultados", "no results"),
+        Text::StatusInvalidDate => ("fecha invalida: {}", "invalid date: {}"),
+        Text::StatusBookmarkError => ("error marcador: {}", "bookmark error: {}"),
+        Text::StatusEmptyName => ("nombre vacio", "empty name"),
+        Text::StatusBookmarkSaved => ("marcador guardado: {} ({})", "bookmark saved: {} ({})"),
+        Text::StatusNoBookmarks => (
+            "no hay marcadores guardados (b para guardar)",
+            "no saved bookmarks (b to save)",
+        ),
+        Text::StatusBookmarkNotFound => ("marcador no encontrado: {}", "bookmark not found: {}"),
+        Text::StatusBookmarkInvalidRange => ("rango invalido ({}): {}", "invalid range ({}): {}"),
+        Text::StatusLoadInterrupted => ("carga interrumpida", "load interrupted"),
+        Text::StatusFormComplete => ("formulario completo", "form complete"),
+        Text::StatusNoProjectWithId => ("no hay proyecto con id {}", "no project with id {}"),
+        Text::ProjectOfClient => (" de {}", " of {}"),
+        Text::StatusAmbiguousProject => (
+            "hay {} proyectos \"{}\"; #{}{}: Enter otra vez para confirmar",
+            "there are {} projects \"{}\"; #{}{}: Enter again to confirm",
+        ),
+        Text::StatusProjectSelected => ("proyecto #{}{} seleccionado", "project #{}{} selected"),
+        Text::StatusClientError => ("error cliente: {}", "client error: {}"),
+        Text::StatusConfiguring => ("Configurando...", "Configuring..."),
+        Text::StatusConfigSavedUnchanged => (
+            "Configuracion guardada (sin cambios)",
+            "Configuration saved (no changes)",
+        ),
+        Text::StatusConfigSaved => ("Configuracion guardada: {}", "Configuration saved: {}"),
+        Text::ChangeToken => ("token actualizado", "token updated"),
+        Text::ChangeBaseUrl => ("base url actualizada a {}", "base url updated to {}"),
+        Text::ChangeRange => ("rango actualizado a {}", "range updated to {}"),
+        Text::ChangeRangeRemoved => ("rango default eliminado", "default range removed"),
+        Text::ChangeTheme => ("tema actualizado a {}", "theme updated to {}"),
+        Text::IssueEmptyDate => ("date vacia", "empty date"),
+        Text::IssueNoProject => (
+            "project_id 0: proyecto no seleccionado",
+            "project_id 0: no project selected",
+        ),
+        Text::IssueEmptyDescription => ("description vacia", "empty description"),
+        Text::IssueInvalidMinutes => (
+            "minutes invalido (0 o formato incorrecto)",
+            "invalid minutes (0 or wrong format)",
+        ),
+        Text::StatusNoTokenPressC => (
+            "No hay token configurado. Presiona c para configurar.",
+            "No token configured. Press c to configure.",
+        ),
+        Text::CancelHint => ("Esc: cancelar", "Esc: cancel"),
+        Text::EditEntryTitle => ("Editar Registro #{}", "Edit Entry #{}"),
+        Text::NewEntryTitle => ("Nuevo Registro", "New Entry"),
+        Text::DateFieldTitle => ("Fecha (YYYY-MM-DD)", "Date (YYYY-MM-DD)"),
+        Text::ProjectFieldSelected => ("Proyecto (Ctrl+D: cambiar)", "Project (Ctrl+D: change)"),
+        Text::ProjectFieldLoading => (
+            "Proyecto (cargando proyectos...)",
+            "Project (loading projects...)",
+        ),
+        Text::ProjectFieldById => (
+            "Proyecto (Enter: buscar por id)",
+            "Project (Enter: search by id)",
+        ),
+        Text::ProjectFieldSearch => (
+            "Proyecto (Busca por nombre o id)",
+            "Project (Search by name or id)",
+        ),
+        Text::DescriptionFieldTitle => ("Descripcion", "Description"),
+        Text::DurationFieldFocused => (
+            "Duracion (HH:MM) Up/Down: ±{} {}",
+            "Duration (HH:MM) Up/Down: ±{} {}",
+        ),
+        Text::DurationFieldTitle => ("Duracion (HH:MM)", "Duration (HH:MM)"),
+        Text::BillableCheckbox => ("{} Es facturable (espacio)", "{} Billable (space)"),
+        Text::OptionsTitle => ("Opciones", "Options"),
+        Text::DuplicateProjectName => (" (nombre repetido)", " (repeated name)"),
+        Text::SubmitEnterHint => ("Enter: crear", "Enter: create"),
+        Text::SubmitCtrlSHint => ("Ctrl+S: crear", "Ctrl+S: create"),
+        Text::RetryHint => (" | Ctrl+R: reintentar", " | Ctrl+R: retry"),
+        Text::EntryFormHelp => (
+            "Tab: siguiente | Shift+Tab: anterior | {} | Ctrl/Alt+Enter: crear y otro | Ctrl+E: campo pendiente | Ctrl+V: payload | Esc: cancelar",
+            "Tab: next | Shift+Tab: previous | {} | Ctrl/Alt+Enter: create and add another | Ctrl+E: pending field | Ctrl+V: payload | Esc: cancel",
+        ),
+        Text::PayloadPreviewTitle => (
+            "Payload {} (sin enviar) - cualquier tecla: cerrar",
+            "Payload {} (not sent) - any key: close",
+        ),
+        Text::ConfigTitle => ("Configuracion Local [{}]", "Local Configuration [{}]"),
+        Text::DefaultRangeCustomTitle => (
+            "Rango Default guardado (custom)",
+            "Saved default range (custom)",
+        ),
+        Text::DefaultRangeListTitle => (
+            "Rango Default guardado (lista)",
+            "Saved default range (list)",
+        ),
+        Text::ThemeFieldTitle => ("Tema (lista)", "Theme (list)"),
+        Text::ConfigHelp => (
+            "Version build: {}\nFormatos de rango: AUTO | AUTO-WEEK | AUTO-MONTH | AUTO-QUARTER | YYYY-MM-DD..YYYY-MM-DD\nVista actual: {} (Ctrl+D: usarla como default)\nTema actual: {} (aplicado: {})\nCatalogo: {}\nTab/Shift+Tab: campo | Up/Down: lista de rango/tema | Ctrl+U: limpiar | Ctrl+R: restablecer | Enter: guardar | Esc: cancelar{}",
+            "Build version: {}\nRange formats: AUTO | AUTO-WEEK | AUTO-MONTH | AUTO-QUARTER | YYYY-MM-DD..YYYY-MM-DD\nCurrent view: {} (Ctrl+D: use it as default)\nCurrent theme: {} (applied: {})\nCatalog: {}\nTab/Shift+Tab: field | Up/Down: range/theme list | Ctrl+U: clear | Ctrl+R: reset | Enter: save | Esc: cancel{}",
+        ),
+        Text::RangesTitle => ("Rangos", "Ranges"),
+        Text::ThemesTitle => ("Temas", "Themes"),
+        Text::BookmarksTitle => (
+            "Marcadores de rango (Enter: aplicar | Esc: cerrar)",
+            "Range bookmarks (Enter: apply | Esc: close)",
+        ),
+        Text::TemplatesTitle => (
+            "Plantillas (Enter: usar | Esc: cerrar)",
+            "Templates (Enter: use | Esc: close)",
+        ),
+        Text::TemplateNonBillable => (" · no facturable", " · non-billable"),
+        Text::SearchTitle => (
+            "Buscar en el rango (proyecto o nota)",
+            "Search the range (project or note)",
+        ),
+        Text::SearchResultsTitle => (
+            "Resultados: {} (Enter: ir | Esc: cerrar)",
+            "Results: {} (Enter: go | Esc: close)",
+        ),
+        Text::HelpUsage => ("Uso:", "Usage:"),
+        Text::HelpApiSubcommands => ("Subcomandos API:", "API subcommands:"),
+        Text::HelpMcpSubcommands => ("Subcomandos MCP:", "MCP subcommands:"),
+        Text::McpHelp => (
+            "Inicia un servidor MCP por stdio para automatizar el TUI con respuestas compactas en TOON.",
+            "Starts a stdio MCP server to automate the TUI with compact TOON responses.",
+        ),
+        Text::McpRecordHelp => (
+            "Con VARTUI_MCP_RECORD=<archivo> cada solicitud, su respuesta y las llamadas al API se agregan como una linea JSON (el token queda como <redacted>); `--replay <archivo>` las reenvia a un servidor nuevo sin usar la red e imprime las respuestas que cambian.",
+            "With VARTUI_MCP_RECORD=<file> every request, its response and the API calls are appended as one JSON line (the token is stored as <redacted>); `--replay <file>` sends them to a fresh server without touching the network and prints the responses that differ.",
+        ),
+        Text::McpInstructions => (
+            "Servidor MCP headless para el TUI. Usa `vartui.session.action` para menos tokens y `view=tiny|none` para respuestas minimas.",
+            "Headless MCP server for the TUI. Use `vartui.session.action` for fewer tokens and `view=tiny|none` for minimal responses.",
+        ),
+    };
+    match language {
+        Language::Es => es,
+        Language::En => en,
+    }
+}
+
+pub fn weekday_short(language: Language, weekday: Weekday) -> &'static str {
+    let (es, en) = match weekday {
+        Weekday::Mon => ("Lun", "Mon"),
+        Weekday::Tue => ("Mar", "Tue"),
+        Weekday::Wed => ("Mie", "Wed"),
+        Weekday::Thu => ("Jue", "Thu"),
+        Weekday::Fri => ("Vie", "Fri"),
+        Weekday::Sat => ("Sab", "Sat"),
+        Weekday::Sun => ("Dom", "Sun"),
+    };
+    match language {
+        Language::Es => es,
+        Language::En => en,
+    }
+}
+
+// Placeholders are filled by position, so an argument containing "{}" is left as is
+pub fn tr_args(language: Language, text: Text, args: &[&str]) -> String {
+    let mut pieces = tr(language, text).split("{}");
+    let mut output = pieces.next().unwrap_or_default().to_string();
+    let mut args = args.iter();
+    for piece in pieces {
+        output.push_str(args.next().copied().unwrap_or("{}"));
+        output.push_str(piece);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tr_args_keeps_braces_inside_arguments() {
+        let text = tr_args(Language::Es, Text::DaySummary, &["{}", "2h", "3"]);
+        assert_eq!(text, "{}: 2h en 3 registros");
+    }
+
+    #[test]
+    fn tr_args_leaves_missing_placeholders() {
+        let text = tr_args(Language::En, Text::DaySummary, &["Mon"]);
+        assert_eq!(text, "Mon: {} across {} entries");
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{Duration, NaiveDateTime, NaiveTime, Utc};
+
+use crate::domain::models::Day;
+use crate::utils::parsing::parse_date;
+
+const DAY_START_HOUR: u32 = 9;
+
+// One VEVENT per entry. Entries are stacked from 09:00; a day whose entries
+// would run past midnight is exported as all-day events instead.
+pub fn days_to_ics(days: &[Day]) -> String {
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//vartui//timesheet//ES".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+
+    for day in days {
+        let Some(date) = parse_date(&day.date) else {
+            continue;
+        };
+        let minutes: Vec<i64> = day
+            .entries
+            .iter()
+            .map(|entry| i64::from(entry.minutes))
+            .collect();
+        let total: i64 = minutes.iter().sum();
+        let all_day = i64::from(DAY_START_HOUR) * 60 + total > 24 * 60;
+
+        let mut start = NaiveDateTime::new(
+            date,
+            NaiveTime::from_hms_opt(DAY_START_HOUR, 0, 0).unwrap_or_default(),
+        );
+        for (index, (entry, minutes)) in day.entries.iter().zip(&minutes).enumerate() {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}-{}@vartui", date.format("%Y%m%d"), index));
+            lines.push(format!("DTSTAMP:{stamp}"));
+            if all_day {
+                lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                lines.push(format!(
+                    "DTEND;VALUE=DATE:{}",
+                    (date + Duration::days(1)).format("%Y%m%d")
+                ));
+            } else {
+                let end = start + Duration::minutes(*minutes);
+                lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")));
+                lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%S")));
+                start = end;
+            }
+            lines.push(format!("SUMMARY:{}", escape_text(&entry.project)));
+            lines.push(format!("DESCRIPTION:{}", escape_text(&entry.note)));
+            lines.push("END:VEVENT".to_string());
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+// RFC 5545: content lines longer than 75 octets continue on lines starting with a space
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(ch);
+        width += ch.len_utf8();
+    }
+    folded
+}
+
+// RFC 5545 TEXT: CRLF and bare CR become \n too, so a note never breaks the content line
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\r', '\n'], "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Entry;
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(escape_text("a;b,c\\d"), "a\\;b\\,c\\\\d");
+        assert_eq!(
+            escape_text("uno\r\ndos\rtres\ncuatro"),
+            "uno\\ndos\\ntres\\ncuatro"
+        );
+    }
+
+    #[test]
+    fn folds_long_lines_at_75_octets() {
+        let line = format!("DESCRIPTION:{}", "x".repeat(100));
+        let folded = fold_line(&line);
+        let parts: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 75);
+        assert!(parts[1].starts_with(' '));
+        assert_eq!(parts.concat().replacen(' ', "", 1), line);
+
+        // Multi-byte characters are never split across lines
+        let folded = fold_line(&"ñ".repeat(60));
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(fold_line("corta"), "corta");
+    }
+
+    #[test]
+    fn stacks_entries_from_nine_and_falls_back_to_all_day() {
+        let days = vec![
+            Day {
+                date: "2026-03-02".to_string(),
+                entries: vec![
+                    Entry::sample("Proyecto, A", 90, "nota\r\nlarga"),
+                    Entry::sample("B", 30, ""),
+                ],
+            },
+            Day {
+                date: "2026-03-03".to_string(),
+                entries: vec![Entry::sample("C", 16 * 60, "")],
+            },
+        ];
+        let ics = days_to_ics(&days);
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+        assert!(ics.contains("UID:20260302-0@vartui\r\n"));
+        assert!(ics.contains("DTSTART:20260302T090000\r\nDTEND:20260302T103000\r\n"));
+        assert!(ics.contains("DTSTART:20260302T103000\r\nDTEND:20260302T110000\r\n"));
+        assert!(ics.contains("SUMMARY:Proyecto\\, A\r\nDESCRIPTION:nota\\nlarga\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260303\r\nDTEND;VALUE=DATE:20260304\r\n"));
+    }
+}
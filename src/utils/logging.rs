@@ -1,10 +1,61 @@
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
 #[macro_export]
 macro_rules! log {
     ($($arg:tt)*) => ({
-        use std::io::Write;
-        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-        if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open("debug.log") {
-            let _ = writeln!(file, "[{}] {}", now, format!($($arg)*));
-        }
+        $crate::utils::logging::write_log_line(&format!($($arg)*));
     })
 }
+
+// VARTUI_LOG_FORMAT=json switches debug.log to one JSON object per line
+fn json_format() -> bool {
+    static JSON: OnceLock<bool> = OnceLock::new();
+    *JSON.get_or_init(|| {
+        std::env::var("VARTUI_LOG_FORMAT")
+            .map(|value| value.trim().eq_ignore_ascii_case("json"))
+            .unwrap_or(false)
+    })
+}
+
+// HTTP tracing of every API call; starts from VARTUI_TRACE and can be toggled at runtime
+fn trace_flag() -> &'static AtomicBool {
+    static TRACE: OnceLock<AtomicBool> = OnceLock::new();
+    TRACE.get_or_init(|| {
+        let enabled = std::env::var("VARTUI_TRACE")
+            .map(|value| matches!(value.trim(), "1" | "true" | "on" | "yes"))
+            .unwrap_or(false);
+        AtomicBool::new(enabled)
+    })
+}
+
+pub fn trace_enabled() -> bool {
+    trace_flag().load(Ordering::Relaxed)
+}
+
+pub fn set_trace_enabled(enabled: bool) {
+    trace_flag().store(enabled, Ordering::Relaxed);
+}
+
+pub fn write_log_line(message: &str) {
+    use std::io::Write;
+    let now = chrono::Local::now();
+    let line = if json_format() {
+        serde_json::json!({
+            "ts": now.to_rfc3339(),
+            "level": "info",
+            "msg": message
+        })
+        .to_string()
+    } else {
+        format!("[{}] {}", now.format("%Y-%m-%d %H:%M:%S"), message)
+    };
+
+    if let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open("debug.log")
+    {
+        let _ = writeln!(file, "{}", line);
+    }
+}
@@ -1,4 +1,7 @@
+pub mod i18n;
+pub mod ics;
 pub mod parsing;
+pub mod timesheet;
 pub mod version;
 #[macro_use]
 pub mod logging;
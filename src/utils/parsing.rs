@@ -1,15 +1,26 @@
 use crate::domain::models::*;
 use std::collections::HashMap;
 
+// Removes entries without logged time; returns how many were dropped
+pub fn drop_zero_minute_entries(days: &mut [Day]) -> usize {
+    let mut dropped = 0;
+    for day in days {
+        let before = day.entries.len();
+        day.entries.retain(|entry| entry.hours > 0.0);
+        dropped += before - day.entries.len();
+    }
+    dropped
+}
+
 pub fn build_days(
     time_entries: Vec<TimeEntry>,
     projects: Vec<Project>,
     start_date: &str,
     end_date: &str,
 ) -> Vec<Day> {
-    let project_map: HashMap<i32, String> = projects
+    let project_map: HashMap<i32, Project> = projects
         .into_iter()
-        .map(|project| (project.id, project.name))
+        .map(|project| (project.id, project))
         .collect();
 
     let mut grouped: HashMap<String, Vec<Entry>> = HashMap::new();
@@ -17,7 +28,7 @@ pub fn build_days(
         let project = if entry.project_id != 0 {
             project_map
                 .get(&entry.project_id)
-                .cloned()
+                .map(|project| project.name.clone())
                 .unwrap_or_else(|| format!("Proyecto {}", entry.project_id))
         } else if let Some(project) = &entry.project {
             if !project.name.trim().is_empty() {
@@ -25,7 +36,7 @@ pub fn build_days(
             } else if project.id != 0 {
                 project_map
                     .get(&project.id)
-                    .cloned()
+                    .map(|project| project.name.clone())
                     .unwrap_or_else(|| format!("Proyecto {}", project.id))
             } else {
                 "Proyecto".to_string()
@@ -35,6 +46,24 @@ pub fn build_days(
         } else {
             "Proyecto".to_string()
         };
+        let project_id = if entry.project_id != 0 {
+            entry.project_id
+        } else {
+            entry.project.as_ref().map_or(0, |project| project.id)
+        };
+        // Name-only entries get their id back from the projects list
+        let project_id = if project_id == 0 {
+            project_map
+                .values()
+                .find(|known| known.name == project)
+                .map_or(0, |known| known.id)
+        } else {
+            project_id
+        };
+        let client = project_map
+            .get(&project_id)
+            .map(|project| project.client_name.clone())
+            .unwrap_or_default();
         let date = if entry.date.trim().is_empty() {
             "sin-fecha".to_string()
         } else {
@@ -47,9 +76,14 @@ pub fn build_days(
         };
         let hours = entry.minutes as f32 / 60.0;
         grouped.entry(date).or_default().push(Entry {
+            id: entry.id,
             project,
+            project_id,
+            minutes: entry.minutes,
             hours,
             note,
+            billable: entry.is_billable.unwrap_or(true),
+            client,
         });
     }
 
@@ -72,7 +106,16 @@ fn normalize_date(date_str: &str) -> String {
     date_str.to_string()
 }
 
+// Datetimes ("2024-01-15T00:00:00Z", "2024-01-15 08:30:00") keep only their date part
 pub fn parse_date(date_str: &str) -> Option<chrono::NaiveDate> {
+    let date_str = date_str.trim();
+    parse_date_only(date_str).or_else(|| {
+        let (date_part, _) = date_str.split_once(['T', ' '])?;
+        parse_date_only(date_part)
+    })
+}
+
+fn parse_date_only(date_str: &str) -> Option<chrono::NaiveDate> {
     chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d")
         .or_else(|_| chrono::NaiveDate::parse_from_str(date_str, "%Y/%m/%d"))
         .or_else(|_| chrono::NaiveDate::parse_from_str(date_str, "%d-%m-%Y"))
@@ -100,6 +143,50 @@ fn build_range_days(
     days
 }
 
+pub fn format_hhmm(hours: f32) -> String {
+    let total_minutes = (hours * 60.0).round().max(0.0) as u32;
+    format!("{}:{:02}", total_minutes / 60, total_minutes % 60)
+}
+
+pub fn format_duration(hours: f32, hhmm: bool) -> String {
+    if hhmm {
+        format_hhmm(hours)
+    } else {
+        format!("{:.1}h", hours)
+    }
+}
+
+// Range/summary totals: thousands separators so monthly and quarterly sums stay readable
+pub fn format_total(hours: f32, hhmm: bool, compact: bool) -> String {
+    let sign = if hours < 0.0 { "-" } else { "" };
+    let hours = hours.abs();
+    if hhmm {
+        let total_minutes = (hours * 60.0).round() as u64;
+        return format!(
+            "{sign}{}:{:02}",
+            group_thousands(total_minutes / 60),
+            total_minutes % 60
+        );
+    }
+    if compact && hours >= 100.0 {
+        return format!("{sign}{}h", group_thousands(hours.round() as u64));
+    }
+    let tenths = (hours * 10.0).round() as u64;
+    format!("{sign}{}.{}h", group_thousands(tenths / 10), tenths % 10)
+}
+
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    grouped
+}
+
 pub fn build_empty_days(range: &crate::domain::models::DateRange) -> Vec<Day> {
     if let (Some(start), Some(end)) = (parse_date(&range.start), parse_date(&range.end)) {
         return build_range_days(HashMap::new(), start, end);
@@ -132,6 +219,15 @@ pub fn parse_date_range(input: &str) -> Result<crate::domain::models::DateRange,
                 end: now.format("%Y-%m-%d").to_string(),
             });
         }
+        "AUTO-QUARTER" | "QUARTER" => {
+            let quarter_month = (now.month0() / 3) * 3 + 1;
+            let start =
+                chrono::NaiveDate::from_ymd_opt(now.year(), quarter_month, 1).unwrap_or(now);
+            return Ok(crate::domain::models::DateRange {
+                start: start.format("%Y-%m-%d").to_string(),
+                end: now.format("%Y-%m-%d").to_string(),
+            });
+        }
         "AUTO-WEEK" | "WEEK" => {
             // Start of week (Monday)
             let weekday = now.weekday().num_days_from_monday();
@@ -148,7 +244,7 @@ pub fn parse_date_range(input: &str) -> Result<crate::domain::models::DateRange,
     let parts: Vec<&str> = input.split("..").collect();
     if parts.len() != 2 {
         return Err(
-            "Formato incorrecto. Use YYYY-MM-DD..YYYY-MM-DD, AUTO, AUTO-WEEK, o AUTO-MONTH"
+            "Formato incorrecto. Use YYYY-MM-DD..YYYY-MM-DD, AUTO, AUTO-WEEK, AUTO-MONTH o AUTO-QUARTER"
                 .to_string(),
         );
     }
@@ -168,3 +264,54 @@ pub fn parse_date_range(input: &str) -> Result<crate::domain::models::DateRange,
         end: end_str.to_string(),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> Option<String> {
+        parse_date(value).map(|date| date.format("%Y-%m-%d").to_string())
+    }
+
+    #[test]
+    fn formats_large_totals_with_separators() {
+        assert_eq!(format_total(7.5, false, false), "7.5h");
+        assert_eq!(format_total(1234.5, false, false), "1,234.5h");
+        assert_eq!(format_total(312.5, false, true), "313h");
+        assert_eq!(format_total(99.5, false, true), "99.5h");
+        assert_eq!(format_total(1000.25, true, false), "1,000:15");
+    }
+
+    #[test]
+    fn parses_date_only_formats() {
+        assert_eq!(date("2024-01-15").as_deref(), Some("2024-01-15"));
+        assert_eq!(date("15/01/2024").as_deref(), Some("2024-01-15"));
+        assert_eq!(date("not a date"), None);
+    }
+
+    #[test]
+    fn parses_rfc3339_datetimes_to_their_date() {
+        assert_eq!(date("2024-01-15T00:00:00Z").as_deref(), Some("2024-01-15"));
+        assert_eq!(
+            date("2024-01-15T23:30:00-05:00").as_deref(),
+            Some("2024-01-15")
+        );
+        assert_eq!(
+            date("2024-01-15T08:00:00.000000Z").as_deref(),
+            Some("2024-01-15")
+        );
+    }
+
+    #[test]
+    fn parses_space_separated_datetimes_to_their_date() {
+        assert_eq!(date("2024-01-15 08:30:00").as_deref(), Some("2024-01-15"));
+        assert_eq!(date("15/01/2024 08:30").as_deref(), Some("2024-01-15"));
+        assert_eq!(date("garbage 2024-01-15"), None);
+    }
+
+    #[test]
+    fn normalizes_datetimes_into_range_keys() {
+        assert_eq!(normalize_date("2024-01-15T00:00:00Z"), "2024-01-15");
+        assert_eq!(normalize_date("unknown"), "unknown");
+    }
+}
@@ -0,0 +1,93 @@
+use crate::domain::models::Day;
+use crate::utils::parsing::{format_duration, format_total};
+
+// Plain-text timesheet for pasting into emails: one block per day with time,
+// oldest first, and a total at the end.
+pub fn days_to_text(days: &[Day]) -> String {
+    let mut days: Vec<&Day> = days.iter().filter(|day| !day.entries.is_empty()).collect();
+    days.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let mut output = String::new();
+    let mut total_minutes = 0;
+    for day in days {
+        let day_minutes = day.total_minutes();
+        total_minutes += day_minutes;
+        output.push_str(&format!("{} ({})\n", day.date, minutes_label(day_minutes)));
+
+        let project_width = day
+            .entries
+            .iter()
+            .map(|entry| entry.project.chars().count())
+            .max()
+            .unwrap_or(0);
+        for entry in &day.entries {
+            let line = format!(
+                "  - {:<project_width$}  {:>5}  {}",
+                entry.project,
+                minutes_label(entry.minutes),
+                entry.note.replace('\n', " "),
+            );
+            output.push_str(line.trim_end());
+            output.push('\n');
+        }
+        output.push('\n');
+    }
+
+    output.push_str(&format!(
+        "Total: {}\n",
+        format_total(total_minutes as f32 / 60.0, false, false)
+    ));
+    output
+}
+
+fn minutes_label(minutes: i32) -> String {
+    format_duration(minutes as f32 / 60.0, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Entry;
+
+    fn day(date: &str, entries: Vec<Entry>) -> Day {
+        Day {
+            date: date.to_string(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn lists_days_oldest_first_with_a_total() {
+        let days = vec![
+            day("2026-03-03", vec![Entry::sample("Beta", 30, "")]),
+            day("2026-03-01", Vec::new()),
+            day(
+                "2026-03-02",
+                vec![
+                    Entry::sample("Alfa", 90, "revision\ncodigo"),
+                    Entry::sample("Proyecto largo", 60, "reunion"),
+                ],
+            ),
+        ];
+        assert_eq!(
+            days_to_text(&days),
+            "2026-03-02 (2.5h)\n\
+             \x20 - Alfa             1.5h  revision codigo\n\
+             \x20 - Proyecto largo   1.0h  reunion\n\
+             \n\
+             2026-03-03 (0.5h)\n\
+             \x20 - Beta   0.5h\n\
+             \n\
+             Total: 3.0h\n"
+        );
+    }
+
+    #[test]
+    fn empty_range_prints_only_the_total() {
+        assert_eq!(days_to_text(&[]), "Total: 0.0h\n");
+        assert_eq!(
+            days_to_text(&[day("2026-03-01", Vec::new())]),
+            "Total: 0.0h\n"
+        );
+    }
+}